@@ -0,0 +1,75 @@
+use std::fs;
+
+use crate::models::decode_as_rule::DecodeAsRule;
+
+pub struct DecodeAsController {
+    rules: Vec<DecodeAsRule>,
+}
+
+impl DecodeAsController {
+    const CONFIG_FILE: &'static str = "decode_as.conf";
+
+    pub fn new() -> DecodeAsController {
+        DecodeAsController {
+            rules: DecodeAsController::load_rules(),
+        }
+    }
+
+    pub fn get_rules(&self) -> &Vec<DecodeAsRule> {
+        &self.rules
+    }
+
+    pub fn add_rule(&mut self, rule: DecodeAsRule) {
+        self.rules.retain(|existing| existing.transport != rule.transport
+            || existing.address != rule.address
+            || existing.first_port != rule.first_port
+            || existing.last_port != rule.last_port);
+        self.rules.push(rule);
+        self.save_rules();
+    }
+
+    pub fn remove_rule(&mut self, index: usize) {
+        if index < self.rules.len() {
+            self.rules.remove(index);
+            self.save_rules();
+        }
+    }
+
+    pub fn clear_rules(&mut self) {
+        self.rules.clear();
+        self.save_rules();
+    }
+
+    fn load_rules() -> Vec<DecodeAsRule> {
+        let content = match fs::read_to_string(DecodeAsController::CONFIG_FILE) {
+            Ok(content) => content,
+            Err(_) => return Vec::new()
+        };
+
+        content
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let rule = DecodeAsRule::parse(line);
+
+                if rule.is_none() {
+                    println!("Ignoring invalid decode as rule: {}", line);
+                }
+
+                rule
+            })
+            .collect()
+    }
+
+    fn save_rules(&self) {
+        let content: String = self.rules
+            .iter()
+            .map(|rule| format!("{}\n", rule.get_config()))
+            .collect();
+
+        if let Err(error) = fs::write(DecodeAsController::CONFIG_FILE, content) {
+            println!("Error while saving decode as rules: {}", error);
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use crate::filters::checksum_filter::ChecksumFilter;
 use crate::filters::destination_filter::DestinationFilter;
+use crate::filters::field_filter::FieldFilter;
+use crate::filters::payload_filter::PayloadFilter;
+use crate::filters::property_filter::PropertyFilter;
 use crate::filters::protocol_filter::ProtocolFilter;
 use crate::filters::source_filter::SourceFilter;
 use crate::traits::packet_filter::PacketFilter;
@@ -15,6 +19,10 @@ impl FilterController {
                 Box::new(SourceFilter::new()),
                 Box::new(DestinationFilter::new()),
                 Box::new(ProtocolFilter::new()),
+                Box::new(PropertyFilter::new()),
+                Box::new(ChecksumFilter::new()),
+                Box::new(PayloadFilter::new()),
+                Box::new(FieldFilter::new()),
             ],
             active_filters: vec![]
         }
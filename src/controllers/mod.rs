@@ -1,3 +1,5 @@
 pub(crate) mod operation_controller;
 pub(crate) mod device_controller;
 pub(crate) mod filter_controller;
+
+pub(crate) mod decode_as_controller;
\ No newline at end of file
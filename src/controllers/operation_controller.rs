@@ -1,6 +1,15 @@
+use crate::operations::arp_analysis_operation::ArpAnalysisOperation;
+use crate::operations::checksum_offload_operation::ChecksumOffloadOperation;
 use crate::operations::clear_collected_packets_operation::ClearCollectedPacketsOperation;
 use crate::operations::configure_filters_operation::ConfigureFiltersOperation;
+use crate::operations::decode_as_operation::DecodeAsOperation;
 use crate::operations::exit_program_operation::ExitProgramOperation;
+use crate::operations::export_packets_operation::ExportPacketsOperation;
+use crate::operations::igmp_analysis_operation::IgmpAnalysisOperation;
+use crate::operations::lazy_dissection_operation::LazyDissectionOperation;
+use crate::operations::neighbors_operation::NeighborsOperation;
+use crate::operations::ntp_analysis_operation::NtpAnalysisOperation;
+use crate::operations::open_capture_file_operation::OpenCaptureFileOperation;
 use crate::operations::select_device_operation::SelectDeviceOperation;
 use crate::operations::start_sniffing_operation::StartSniffingOperation;
 use crate::operations::traffic_analysis_operation::TrafficAnalysisOperation;
@@ -16,10 +25,19 @@ impl OperationController {
         vec![
             Box::new(SelectDeviceOperation::new()),
             Box::new(StartSniffingOperation::new()),
+            Box::new(OpenCaptureFileOperation::new()),
             Box::new(ClearCollectedPacketsOperation::new()),
             Box::new(ViewPacketsOperation::new()),
+            Box::new(ExportPacketsOperation::new()),
             Box::new(ConfigureFiltersOperation::new()),
+            Box::new(DecodeAsOperation::new()),
+            Box::new(ChecksumOffloadOperation::new()),
+            Box::new(LazyDissectionOperation::new()),
             Box::new(TrafficAnalysisOperation::new()),
+            Box::new(ArpAnalysisOperation::new()),
+            Box::new(NeighborsOperation::new()),
+            Box::new(NtpAnalysisOperation::new()),
+            Box::new(IgmpAnalysisOperation::new()),
             Box::new(ExitProgramOperation::new()),
         ]
     }
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use pnet::util::MacAddr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Address {
+    Mac(MacAddr),
+    IPv4(Ipv4Addr),
+    IPv6(Ipv6Addr),
+    Port(u16),
+}
+
+impl Address {
+    /// Parses user input, tolerating leading zeros in IPv4 octets, any IPv6 notation and `-` separated MACs
+    pub fn parse(value: &str) -> Option<Address> {
+        let value = value.trim();
+
+        Address::parse_ipv4(value)
+            .or_else(|| value.parse().ok().map(Address::IPv6))
+            .or_else(|| value.replace('-', ":").parse().ok().map(Address::Mac))
+            .or_else(|| Address::parse_number(value).map(Address::Port))
+    }
+
+    fn parse_ipv4(value: &str) -> Option<Address> {
+        let octets: Vec<u8> = value
+            .split('.')
+            .map(Address::parse_number)
+            .collect::<Option<Vec<u8>>>()?;
+
+        match octets.as_slice() {
+            [a, b, c, d] => Some(Address::IPv4(Ipv4Addr::new(*a, *b, *c, *d))),
+            _ => None
+        }
+    }
+
+    fn parse_number<T: std::str::FromStr>(value: &str) -> Option<T> {
+        if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        value.parse().ok()
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Address::Mac(address) => write!(f, "{}", address),
+            Address::IPv4(address) => write!(f, "{}", address),
+            Address::IPv6(address) => write!(f, "{}", address),
+            Address::Port(port) => write!(f, "{}", port),
+        }
+    }
+}
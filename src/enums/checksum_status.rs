@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    Correct,
+    Incorrect(u16),
+    Missing,
+    Unverified(&'static str),
+}
+
+impl ChecksumStatus {
+    pub fn is_incorrect(&self) -> bool {
+        matches!(self, ChecksumStatus::Incorrect(_))
+    }
+}
+
+impl fmt::Display for ChecksumStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChecksumStatus::Correct => write!(f, "correct"),
+            ChecksumStatus::Incorrect(expected) => write!(f, "incorrect (should be 0x{:04x})", expected),
+            ChecksumStatus::Missing => write!(f, "missing"),
+            ChecksumStatus::Unverified(reason) => write!(f, "unverified ({})", reason),
+        }
+    }
+}
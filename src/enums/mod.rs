@@ -1 +1,5 @@
 pub(crate) mod protocol;
+
+pub(crate) mod checksum_status;
+
+pub(crate) mod address;
\ No newline at end of file
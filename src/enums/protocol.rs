@@ -1,11 +1,38 @@
 use strum_macros::{Display, EnumIter, FromRepr};
 
-#[derive(Debug, EnumIter, Display, FromRepr, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, EnumIter, Display, FromRepr, PartialEq, Eq, Hash)]
 pub enum Protocol {
     ARP,
     Ethernet,
     IPv4,
     IPv6,
     TCP,
-    UDP
+    UDP,
+    HTTP,
+    TLS,
+    QUIC,
+    IPv6HopByHop,
+    IPv6Routing,
+    IPv6Fragment,
+    IPv6DestinationOptions,
+    AH,
+    ESP,
+    Reassembled,
+    GRE,
+    ERSPAN,
+    VXLAN,
+    Geneve,
+    MPLS,
+    LLC,
+    LLDP,
+    CDP,
+    STP,
+    NTP,
+    IGMP,
+    SCTP,
+    ICMP,
+    ICMPv6,
+    DNS,
+    Malformed,
+    Data
 }
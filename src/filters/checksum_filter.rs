@@ -0,0 +1,48 @@
+use crate::enums::protocol::Protocol;
+use crate::traits::packet_analysis::PacketAnalysis;
+use crate::traits::packet_filter::PacketFilter;
+
+pub struct ChecksumFilter {}
+
+impl ChecksumFilter {
+    pub fn new() -> ChecksumFilter {
+        ChecksumFilter {}
+    }
+
+    fn print_configurator_instructions(&self) {
+        println!("------ Checksum filter configurator ------");
+        println!("|");
+        println!("| Shows only packets with an incorrect IPv4, TCP, UDP or ICMP checksum.");
+        println!("| On hosts with checksum offload enable the checksum offload option");
+        println!("| so outgoing packets are not reported as incorrect.");
+        println!("|");
+    }
+}
+
+impl PacketFilter for ChecksumFilter {
+    fn apply(&self, packet: &Box<dyn PacketAnalysis>) -> bool {
+        packet.checksum_status().is_some_and(|status| status.is_incorrect())
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Filtering by invalid checksum")
+    }
+
+    fn get_config(&self) -> String {
+        String::from("Filtering by invalid checksum")
+    }
+
+    fn configure(&mut self) {
+        self.print_configurator_instructions();
+    }
+
+    fn is_applicable(&self, protocol: Protocol) -> bool {
+        matches!(protocol, Protocol::IPv4 | Protocol::TCP | Protocol::UDP | Protocol::ICMP | Protocol::ICMPv6)
+    }
+
+    fn create_filter(&self) -> Box<dyn PacketFilter> {
+        self.print_configurator_instructions();
+
+        Box::new(ChecksumFilter::new())
+    }
+}
@@ -1,19 +1,20 @@
 use std::io::Write;
 
 use crate::enums::protocol::Protocol;
+use crate::models::address_pattern::AddressPattern;
 use crate::traits::packet_analysis::PacketAnalysis;
 use crate::traits::packet_filter::PacketFilter;
 use crate::utils::protocol_util::select_protocol;
 
 pub struct DestinationFilter {
-    accepted_destination: String,
+    accepted_destination: AddressPattern,
     applicable_protocol: Protocol,
 }
 
 impl DestinationFilter {
     pub fn new() -> DestinationFilter {
         DestinationFilter {
-            accepted_destination: String::from(""),
+            accepted_destination: AddressPattern::new(),
             applicable_protocol: Protocol::IPv4,
         }
     }
@@ -21,8 +22,15 @@ impl DestinationFilter {
     fn print_configurator_instructions(&self) {
         println!("------ Destination filter configurator ------");
         println!("|");
-        println!("| For the filter to be applied you must set the destination address");
-        println!("| and protocol to which the filter will be applied.");
+        println!("| For the filter to be applied you must set the protocol to which");
+        println!("| the filter will be applied and the destination address.");
+        println!("| Addresses and ports are compared by value, so 010.0.0.1 matches 10.0.0.1");
+        println!("|");
+        println!("| Besides single addresses the following patterns are accepted:");
+        println!("| - IPv4 and IPv6 subnets, e.g. '10.0.0.0/8' or 'fe80::/10'");
+        println!("| - IPv4, IPv6 and port ranges, e.g. '10.0.0.1-10.0.0.50' or '8000-8100'");
+        println!("| - MAC vendor (OUI) prefixes, e.g. '00:1b:21'");
+        println!("| - any of the above negated with a leading '!', e.g. '!192.168.0.0/16'");
         println!("|");
     }
 
@@ -34,13 +42,13 @@ impl DestinationFilter {
         println!("|");
     }
 
-    fn collect_destination_address(&self) -> Option<String> {
+    fn collect_destination_address(&self, protocol: Protocol) -> Option<AddressPattern> {
         let mut destination_address = String::new();
         print!("[Destination Filter] Enter destination address > ");
         std::io::stdout().flush().unwrap();
 
         match std::io::stdin().read_line(&mut destination_address) {
-            Ok(_) => Some(destination_address.trim().to_string()),
+            Ok(_) => AddressPattern::parse(destination_address.as_str(), protocol),
             Err(_) => None
         }
     }
@@ -52,7 +60,10 @@ impl DestinationFilter {
 
 impl PacketFilter for DestinationFilter {
     fn apply(&self, packet: &Box<dyn PacketAnalysis>) -> bool {
-        packet.destination() == self.accepted_destination
+        match packet.destination_address() {
+            Some(address) => self.accepted_destination.matches(address),
+            None => self.accepted_destination.matches_text(packet.destination().as_str())
+        }
     }
 
     fn get_description(&self) -> String {
@@ -67,20 +78,21 @@ impl PacketFilter for DestinationFilter {
         self.print_configurator_instructions();
         self.print_current_config();
 
-        let destination_address = self.collect_destination_address();
-        let protocol = self.collect_protocol();
-
-        match destination_address {
-            Some(destination_address) => self.accepted_destination = destination_address,
-            None => println!("| Given destination address is invalid, sticking to the previous one {}", self.accepted_destination)
-
+        let protocol = match self.collect_protocol() {
+            Some(protocol) => protocol,
+            None => {
+                println!("| Given protocol is invalid, sticking to the previous one {:?}", self.applicable_protocol);
+                return;
+            }
+        };
+
+        match self.collect_destination_address(protocol) {
+            Some(destination_address) => {
+                self.accepted_destination = destination_address;
+                self.applicable_protocol = protocol;
+            },
+            None => println!("| Given destination address is invalid for {}, sticking to the previous one {} [{}]", protocol, self.accepted_destination, self.applicable_protocol)
         }
-
-        match protocol {
-            Some(protocol) => self.applicable_protocol = protocol,
-            None => println!("| Given protocol is invalid, sticking to the previous one {:?}", self.applicable_protocol)
-        }
-
     }
 
     fn is_applicable(&self, protocol: Protocol) -> bool {
@@ -90,13 +102,6 @@ impl PacketFilter for DestinationFilter {
     fn create_filter(&self) -> Box<dyn PacketFilter> {
         self.print_configurator_instructions();
 
-        let mut destination_address = self.collect_destination_address();
-
-        while destination_address.is_none() {
-            println!("| Given destination address is invalid, try again");
-            destination_address = self.collect_destination_address();
-        }
-
         let mut protocol = self.collect_protocol();
 
         while protocol.is_none() {
@@ -104,9 +109,17 @@ impl PacketFilter for DestinationFilter {
             protocol = self.collect_protocol();
         }
 
+        let protocol = protocol.unwrap();
+        let mut destination_address = self.collect_destination_address(protocol);
+
+        while destination_address.is_none() {
+            println!("| Given destination address is invalid for {}, try again", protocol);
+            destination_address = self.collect_destination_address(protocol);
+        }
+
         Box::new(DestinationFilter {
             accepted_destination: destination_address.unwrap(),
-            applicable_protocol: protocol.unwrap(),
+            applicable_protocol: protocol,
         })
     }
 }
\ No newline at end of file
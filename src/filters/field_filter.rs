@@ -0,0 +1,157 @@
+use std::io::Write;
+
+use crate::enums::protocol::Protocol;
+use crate::models::packet_field::{FieldValue, PacketField};
+use crate::traits::packet_analysis::PacketAnalysis;
+use crate::traits::packet_filter::PacketFilter;
+
+pub struct FieldFilter {
+    field_name: String,
+    comparison: Option<(String, String)>,
+}
+
+impl FieldFilter {
+    const OPERATORS: [&'static str; 5] = ["==", "!=", ">", "<", "contains"];
+
+    pub fn new() -> FieldFilter {
+        FieldFilter {
+            field_name: String::from(""),
+            comparison: None,
+        }
+    }
+
+    fn print_configurator_instructions(&self) {
+        println!("------ Field filter configurator ------");
+        println!("|");
+        println!("| For the filter to be applied you must set the field expression.");
+        println!("| An expression is a field name, optionally followed by an operator and a value");
+        println!("| Available operators: {}", FieldFilter::OPERATORS.join(", "));
+        println!("| e.g. 'tcp.options.mss_val', 'ip.ttl < 64', 'tcp.dstport == 443' or 'http.host contains example'");
+        println!("|");
+        println!("| Field names are listed together with their values in the packet details export");
+        println!("|");
+    }
+
+    fn print_current_config(&self) {
+        println!("------ Current configuration ------");
+        println!("|");
+        println!("| Current field expression: {}", self.get_expression());
+        println!("|");
+    }
+
+    fn get_expression(&self) -> String {
+        match &self.comparison {
+            Some((operator, value)) => format!("{} {} {}", self.field_name, operator, value),
+            None => self.field_name.clone()
+        }
+    }
+
+    fn collect_expression(&self) -> Option<(String, Option<(String, String)>)> {
+        let mut expression = String::new();
+        print!("[Field Filter] Enter field expression > ");
+        std::io::stdout().flush().unwrap();
+
+        if std::io::stdin().read_line(&mut expression).is_err() {
+            return None;
+        }
+
+        let expression = expression.trim();
+        let (field_name, comparison) = match expression.split_once(char::is_whitespace) {
+            Some((field_name, comparison)) => (field_name, Some(comparison.trim())),
+            None => (expression, None)
+        };
+
+        if field_name.is_empty() {
+            return None;
+        }
+
+        let comparison = match comparison {
+            Some(comparison) => {
+                let (operator, value) = comparison.split_once(char::is_whitespace)?;
+
+                if !FieldFilter::OPERATORS.contains(&operator) {
+                    return None;
+                }
+
+                let value = value.trim().to_string();
+
+                if matches!(operator, ">" | "<") && FieldValue::parse_number(value.as_str()).is_none() {
+                    return None;
+                }
+
+                Some((operator.to_string(), value))
+            },
+            None => None
+        };
+
+        Some((field_name.to_string(), comparison))
+    }
+
+    fn matches(&self, field: &PacketField) -> bool {
+        let (operator, value) = match &self.comparison {
+            Some(comparison) => comparison,
+            None => return true
+        };
+
+        match operator.as_str() {
+            "==" => field.value.matches(value),
+            "!=" => !field.value.matches(value),
+            ">" => field.value.as_number().zip(FieldValue::parse_number(value)).is_some_and(|(field, value)| field > value),
+            "<" => field.value.as_number().zip(FieldValue::parse_number(value)).is_some_and(|(field, value)| field < value),
+            "contains" => field.get_display().to_lowercase().contains(value.to_lowercase().as_str()),
+            _ => false
+        }
+    }
+}
+
+impl PacketFilter for FieldFilter {
+    fn apply(&self, packet: &Box<dyn PacketAnalysis>) -> bool {
+        packet.fields()
+            .iter()
+            .flat_map(|field| field.find(self.field_name.as_str()))
+            .any(|field| self.matches(field))
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Filtering by field")
+    }
+
+    fn get_config(&self) -> String {
+        format!("Filtering by field: {}", self.get_expression())
+    }
+
+    fn configure(&mut self) {
+        self.print_configurator_instructions();
+        self.print_current_config();
+
+        match self.collect_expression() {
+            Some((field_name, comparison)) => {
+                self.field_name = field_name;
+                self.comparison = comparison;
+            },
+            None => println!("| Given field expression is invalid, sticking to the previous one {}", self.get_expression())
+        }
+    }
+
+    fn is_applicable(&self, _protocol: Protocol) -> bool {
+        true
+    }
+
+    fn create_filter(&self) -> Box<dyn PacketFilter> {
+        self.print_configurator_instructions();
+
+        let mut expression = self.collect_expression();
+
+        while expression.is_none() {
+            println!("| Given field expression is invalid, try again");
+            expression = self.collect_expression();
+        }
+
+        let (field_name, comparison) = expression.unwrap();
+
+        Box::new(FieldFilter {
+            field_name,
+            comparison,
+        })
+    }
+}
@@ -1,3 +1,7 @@
 pub(crate) mod source_filter;
 pub(crate) mod destination_filter;
-pub(crate) mod protocol_filter;
\ No newline at end of file
+pub(crate) mod protocol_filter;
+pub(crate) mod property_filter;
+pub(crate) mod checksum_filter;
+pub(crate) mod payload_filter;
+pub(crate) mod field_filter;
\ No newline at end of file
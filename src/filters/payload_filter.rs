@@ -0,0 +1,137 @@
+use std::io::Write;
+
+use crate::enums::protocol::Protocol;
+use crate::models::packet_field::FieldValue;
+use crate::traits::packet_analysis::PacketAnalysis;
+use crate::traits::packet_filter::PacketFilter;
+use crate::utils::protocol_util::select_protocol;
+
+pub struct PayloadFilter {
+    accepted_pattern: String,
+    pattern_bytes: Vec<u8>,
+    applicable_protocol: Protocol,
+}
+
+impl PayloadFilter {
+    pub fn new() -> PayloadFilter {
+        PayloadFilter {
+            accepted_pattern: String::from(""),
+            pattern_bytes: Vec::new(),
+            applicable_protocol: Protocol::Data,
+        }
+    }
+
+    fn print_configurator_instructions(&self) {
+        println!("------ Payload filter configurator ------");
+        println!("|");
+        println!("| For the filter to be applied you must set the payload pattern");
+        println!("| and protocol to which the filter will be applied.");
+        println!("|");
+        println!("| The pattern is matched as text, or as bytes when prefixed with 0x (e.g. 0x160301)");
+        println!("| Use the Data protocol to match bytes left over after dissection");
+        println!("|");
+    }
+
+    fn print_current_config(&self) {
+        println!("------ Current configuration ------");
+        println!("|");
+        println!("| Current payload pattern: {}", self.accepted_pattern);
+        println!("| Current applicable protocol: {:?}", self.applicable_protocol);
+        println!("|");
+    }
+
+    fn collect_pattern(&self) -> Option<(String, Vec<u8>)> {
+        let mut pattern = String::new();
+        print!("[Payload Filter] Enter payload pattern > ");
+        std::io::stdout().flush().unwrap();
+
+        if std::io::stdin().read_line(&mut pattern).is_err() {
+            return None;
+        }
+
+        let pattern = pattern.trim().to_string();
+
+        if pattern.is_empty() {
+            return None;
+        }
+
+        let pattern_bytes = if pattern.starts_with("0x") {
+            FieldValue::parse_bytes(&pattern)?
+        } else {
+            pattern.as_bytes().to_vec()
+        };
+
+        Some((pattern, pattern_bytes))
+    }
+
+    fn collect_protocol(&self) -> Option<Protocol> {
+        select_protocol()
+    }
+}
+
+impl PacketFilter for PayloadFilter {
+    fn apply(&self, packet: &Box<dyn PacketAnalysis>) -> bool {
+        !self.pattern_bytes.is_empty() && packet.payload()
+            .windows(self.pattern_bytes.len())
+            .any(|window| window == self.pattern_bytes.as_slice())
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Filtering by payload content")
+    }
+
+    fn get_config(&self) -> String {
+        format!("Filtering by payload content: {} [{}]", self.accepted_pattern, self.applicable_protocol)
+    }
+
+    fn configure(&mut self) {
+        self.print_configurator_instructions();
+        self.print_current_config();
+
+        let pattern = self.collect_pattern();
+        let protocol = self.collect_protocol();
+
+        match pattern {
+            Some((pattern, pattern_bytes)) => {
+                self.accepted_pattern = pattern;
+                self.pattern_bytes = pattern_bytes;
+            },
+            None => println!("| Given payload pattern is invalid, sticking to the previous one {}", self.accepted_pattern)
+        }
+
+        match protocol {
+            Some(protocol) => self.applicable_protocol = protocol,
+            None => println!("| Given protocol is invalid, sticking to the previous one {:?}", self.applicable_protocol)
+        }
+    }
+
+    fn is_applicable(&self, protocol: Protocol) -> bool {
+        protocol == self.applicable_protocol
+    }
+
+    fn create_filter(&self) -> Box<dyn PacketFilter> {
+        self.print_configurator_instructions();
+
+        let mut pattern = self.collect_pattern();
+
+        while pattern.is_none() {
+            println!("| Given payload pattern is invalid, try again");
+            pattern = self.collect_pattern();
+        }
+
+        let mut protocol = self.collect_protocol();
+
+        while protocol.is_none() {
+            println!("| Given protocol is invalid, try again");
+            protocol = self.collect_protocol();
+        }
+
+        let (accepted_pattern, pattern_bytes) = pattern.unwrap();
+
+        Box::new(PayloadFilter {
+            accepted_pattern,
+            pattern_bytes,
+            applicable_protocol: protocol.unwrap(),
+        })
+    }
+}
@@ -0,0 +1,124 @@
+use std::io::Write;
+
+use crate::enums::protocol::Protocol;
+use crate::traits::packet_analysis::PacketAnalysis;
+use crate::traits::packet_filter::PacketFilter;
+use crate::utils::protocol_util::select_protocol;
+
+pub struct PropertyFilter {
+    accepted_property: String,
+    applicable_protocol: Protocol,
+}
+
+impl PropertyFilter {
+    pub fn new() -> PropertyFilter {
+        PropertyFilter {
+            accepted_property: String::from(""),
+            applicable_protocol: Protocol::IPv4,
+        }
+    }
+
+    fn print_configurator_instructions(&self) {
+        println!("------ Property filter configurator ------");
+        println!("|");
+        println!("| For the filter to be applied you must set the property");
+        println!("| and protocol to which the filter will be applied.");
+        println!("|");
+        println!("| IPv4 properties: DF, MF, FRAGMENT, option names (RR, TS, RA, LSRR, SSRR),");
+        println!("| DSCP classes (CS0-CS7, AF11-AF43, EF, VA, LE) and ECN (Not-ECT, ECT(0), ECT(1), CE)");
+        println!("| TCP properties: flags (SYN, ACK, FIN, RST, PSH, URG, ECE, CWR)");
+        println!("| and options (MSS, WS, SACK_PERM, SACK, TS, TFO, MPTCP)");
+        println!("| ARP properties: operations (REQUEST, REPLY, GRATUITOUS, PROBE, ANNOUNCEMENT)");
+        println!("| STP properties: TCN, TC, TCA, PROPOSAL, AGREEMENT, LEARNING, FORWARDING");
+        println!("| SCTP properties: chunk types (DATA, INIT, INIT_ACK, SACK, HEARTBEAT, ABORT, SHUTDOWN, ...)");
+        println!("| Data properties: TEXT, HIGH_ENTROPY");
+        println!("| Malformed properties: TOO_SHORT, BAD_HEADER_LENGTH, TOTAL_LENGTH_EXCEEDS_CAPTURE, SNAPLEN_TRUNCATION");
+        println!("|");
+    }
+
+    fn print_current_config(&self) {
+        println!("------ Current configuration ------");
+        println!("|");
+        println!("| Current property: {}", self.accepted_property);
+        println!("| Current applicable protocol: {:?}", self.applicable_protocol);
+        println!("|");
+    }
+
+    fn collect_property(&self) -> Option<String> {
+        let mut property = String::new();
+        print!("[Property Filter] Enter property > ");
+        std::io::stdout().flush().unwrap();
+
+        match std::io::stdin().read_line(&mut property) {
+            Ok(_) => Some(property.trim().to_string()),
+            Err(_) => None
+        }
+    }
+
+    fn collect_protocol(&self) -> Option<Protocol> {
+        select_protocol()
+    }
+}
+
+impl PacketFilter for PropertyFilter {
+    fn apply(&self, packet: &Box<dyn PacketAnalysis>) -> bool {
+        packet.properties()
+            .iter()
+            .any(|property| property.eq_ignore_ascii_case(&self.accepted_property))
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Filtering by property")
+    }
+
+    fn get_config(&self) -> String {
+        format!("Filtering by property: {} [{}]", self.accepted_property, self.applicable_protocol)
+    }
+
+    fn configure(&mut self) {
+        self.print_configurator_instructions();
+        self.print_current_config();
+
+        let property = self.collect_property();
+        let protocol = self.collect_protocol();
+
+        match property {
+            Some(property) => self.accepted_property = property,
+            None => println!("| Given property is invalid, sticking to the previous one {}", self.accepted_property)
+
+        }
+
+        match protocol {
+            Some(protocol) => self.applicable_protocol = protocol,
+            None => println!("| Given protocol is invalid, sticking to the previous one {:?}", self.applicable_protocol)
+        }
+
+    }
+
+    fn is_applicable(&self, protocol: Protocol) -> bool {
+        protocol == self.applicable_protocol
+    }
+
+    fn create_filter(&self) -> Box<dyn PacketFilter> {
+        self.print_configurator_instructions();
+
+        let mut property = self.collect_property();
+
+        while property.is_none() {
+            println!("| Given property is invalid, try again");
+            property = self.collect_property();
+        }
+
+        let mut protocol = self.collect_protocol();
+
+        while protocol.is_none() {
+            println!("| Given protocol is invalid, try again");
+            protocol = self.collect_protocol();
+        }
+
+        Box::new(PropertyFilter {
+            accepted_property: property.unwrap(),
+            applicable_protocol: protocol.unwrap(),
+        })
+    }
+}
\ No newline at end of file
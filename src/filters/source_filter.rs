@@ -1,19 +1,20 @@
 use std::io::Write;
 
 use crate::enums::protocol::Protocol;
+use crate::models::address_pattern::AddressPattern;
 use crate::traits::packet_analysis::PacketAnalysis;
 use crate::traits::packet_filter::PacketFilter;
 use crate::utils::protocol_util::select_protocol;
 
 pub struct SourceFilter {
-    accepted_source: String,
+    accepted_source: AddressPattern,
     applicable_protocol: Protocol,
 }
 
 impl SourceFilter {
     pub fn new() -> SourceFilter {
         SourceFilter {
-            accepted_source: String::from(""),
+            accepted_source: AddressPattern::new(),
             applicable_protocol: Protocol::IPv4,
         }
     }
@@ -21,8 +22,15 @@ impl SourceFilter {
     fn print_configurator_instructions(&self) {
         println!("------ Source filter configurator ------");
         println!("|");
-        println!("| For the filter to be applied you must set the source address");
-        println!("| and protocol to which the filter will be applied.");
+        println!("| For the filter to be applied you must set the protocol to which");
+        println!("| the filter will be applied and the source address.");
+        println!("| Addresses and ports are compared by value, so 010.0.0.1 matches 10.0.0.1");
+        println!("|");
+        println!("| Besides single addresses the following patterns are accepted:");
+        println!("| - IPv4 and IPv6 subnets, e.g. '10.0.0.0/8' or 'fe80::/10'");
+        println!("| - IPv4, IPv6 and port ranges, e.g. '10.0.0.1-10.0.0.50' or '8000-8100'");
+        println!("| - MAC vendor (OUI) prefixes, e.g. '00:1b:21'");
+        println!("| - any of the above negated with a leading '!', e.g. '!192.168.0.0/16'");
         println!("|");
     }
 
@@ -34,13 +42,13 @@ impl SourceFilter {
         println!("|");
     }
 
-    fn collect_source_address(&self) -> Option<String> {
+    fn collect_source_address(&self, protocol: Protocol) -> Option<AddressPattern> {
         let mut source_address = String::new();
         print!("[Source Filter] Enter source address > ");
         std::io::stdout().flush().unwrap();
 
         match std::io::stdin().read_line(&mut source_address) {
-            Ok(_) => Some(source_address.trim().to_string()),
+            Ok(_) => AddressPattern::parse(source_address.as_str(), protocol),
             Err(_) => None
         }
     }
@@ -52,7 +60,10 @@ impl SourceFilter {
 
 impl PacketFilter for SourceFilter {
     fn apply(&self, packet: &Box<dyn PacketAnalysis>) -> bool {
-        packet.source() == self.accepted_source
+        match packet.source_address() {
+            Some(address) => self.accepted_source.matches(address),
+            None => self.accepted_source.matches_text(packet.source().as_str())
+        }
     }
 
     fn get_description(&self) -> String {
@@ -67,20 +78,21 @@ impl PacketFilter for SourceFilter {
         self.print_configurator_instructions();
         self.print_current_config();
 
-        let source_address = self.collect_source_address();
-        let protocol = self.collect_protocol();
-
-        match source_address {
-            Some(source_address) => self.accepted_source = source_address,
-            None => println!("| Given source address is invalid, sticking to the previous one {}", self.accepted_source)
-
+        let protocol = match self.collect_protocol() {
+            Some(protocol) => protocol,
+            None => {
+                println!("| Given protocol is invalid, sticking to the previous one {:?}", self.applicable_protocol);
+                return;
+            }
+        };
+
+        match self.collect_source_address(protocol) {
+            Some(source_address) => {
+                self.accepted_source = source_address;
+                self.applicable_protocol = protocol;
+            },
+            None => println!("| Given source address is invalid for {}, sticking to the previous one {} [{}]", protocol, self.accepted_source, self.applicable_protocol)
         }
-
-        match protocol {
-            Some(protocol) => self.applicable_protocol = protocol,
-            None => println!("| Given protocol is invalid, sticking to the previous one {:?}", self.applicable_protocol)
-        }
-
     }
 
     fn is_applicable(&self, protocol: Protocol) -> bool {
@@ -90,13 +102,6 @@ impl PacketFilter for SourceFilter {
     fn create_filter(&self) -> Box<dyn PacketFilter> {
         self.print_configurator_instructions();
 
-        let mut source_address = self.collect_source_address();
-
-        while source_address.is_none() {
-            println!("| Given source address is invalid, try again");
-            source_address = self.collect_source_address();
-        }
-
         let mut protocol = self.collect_protocol();
 
         while protocol.is_none() {
@@ -104,9 +109,17 @@ impl PacketFilter for SourceFilter {
             protocol = self.collect_protocol();
         }
 
+        let protocol = protocol.unwrap();
+        let mut source_address = self.collect_source_address(protocol);
+
+        while source_address.is_none() {
+            println!("| Given source address is invalid for {}, try again", protocol);
+            source_address = self.collect_source_address(protocol);
+        }
+
         Box::new(SourceFilter {
             accepted_source: source_address.unwrap(),
-            applicable_protocol: protocol.unwrap(),
+            applicable_protocol: protocol,
         })
     }
 }
\ No newline at end of file
@@ -0,0 +1,258 @@
+use std::fmt;
+
+use crate::enums::address::Address;
+use crate::enums::protocol::Protocol;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AddressMatcher {
+    Exact(Address),
+    Subnet(Address, u8),
+    Range(Address, Address),
+    Oui([u8; 3]),
+    Text(String),
+}
+
+/// Source or destination pattern, e.g. `10.0.0.0/8`, `fe80::/10`, `10.0.0.1-10.0.0.50`,
+/// `00:1b:21`, `8000-8100`, optionally negated with a leading `!`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressPattern {
+    negated: bool,
+    matcher: AddressMatcher,
+}
+
+impl AddressPattern {
+    pub fn new() -> AddressPattern {
+        AddressPattern {
+            negated: false,
+            matcher: AddressMatcher::Text(String::new()),
+        }
+    }
+
+    /// Parses the pattern, rejecting forms that cannot match the addresses carried by the protocol
+    pub fn parse(value: &str, protocol: Protocol) -> Option<AddressPattern> {
+        let value = value.trim();
+        let (negated, value) = match value.strip_prefix('!') {
+            Some(value) => (true, value.trim()),
+            None => (false, value)
+        };
+
+        let matcher = if AddressPattern::has_typed_addresses(protocol) {
+            AddressPattern::parse_matcher(value, protocol)?
+        } else {
+            AddressMatcher::Text(value.to_string())
+        };
+
+        Some(AddressPattern { negated, matcher })
+    }
+
+    pub fn matches(&self, address: Address) -> bool {
+        let matched = match &self.matcher {
+            AddressMatcher::Exact(expected) => *expected == address,
+            AddressMatcher::Subnet(network, prefix_length) => AddressPattern::in_subnet(*network, *prefix_length, address),
+            AddressMatcher::Range(first, last) => AddressPattern::in_range(*first, *last, address),
+            AddressMatcher::Oui(oui) => match address {
+                Address::Mac(mac) => [mac.0, mac.1, mac.2] == *oui,
+                _ => false
+            },
+            AddressMatcher::Text(_) => false
+        };
+
+        matched != self.negated
+    }
+
+    pub fn matches_text(&self, text: &str) -> bool {
+        let matched = match &self.matcher {
+            AddressMatcher::Text(expected) => expected == text,
+            _ => false
+        };
+
+        matched != self.negated
+    }
+
+    fn has_typed_addresses(protocol: Protocol) -> bool {
+        matches!(
+            protocol,
+            Protocol::Ethernet | Protocol::STP | Protocol::ARP | Protocol::IPv4 | Protocol::IGMP
+                | Protocol::IPv6 | Protocol::TCP | Protocol::UDP | Protocol::SCTP
+        )
+    }
+
+    fn is_carried_by(address: &Address, protocol: Protocol) -> bool {
+        match address {
+            Address::Mac(_) => matches!(protocol, Protocol::Ethernet | Protocol::STP),
+            Address::IPv4(_) => matches!(protocol, Protocol::ARP | Protocol::IPv4 | Protocol::IGMP),
+            Address::IPv6(_) => protocol == Protocol::IPv6,
+            Address::Port(_) => matches!(protocol, Protocol::TCP | Protocol::UDP | Protocol::SCTP),
+        }
+    }
+
+    fn parse_matcher(value: &str, protocol: Protocol) -> Option<AddressMatcher> {
+        let same_kind = |address: &Address| AddressPattern::is_carried_by(address, protocol);
+
+        if let Some(address) = Address::parse(value).filter(same_kind) {
+            return Some(AddressMatcher::Exact(address));
+        }
+
+        if let Some((network, prefix_length)) = value.split_once('/') {
+            let network = Address::parse(network).filter(same_kind)?;
+            let prefix_length: u8 = prefix_length.trim().parse().ok()?;
+
+            return match network {
+                Address::IPv4(_) if prefix_length <= 32 => Some(AddressMatcher::Subnet(network, prefix_length)),
+                Address::IPv6(_) if prefix_length <= 128 => Some(AddressMatcher::Subnet(network, prefix_length)),
+                _ => None
+            };
+        }
+
+        if let Some((first, last)) = value.split_once('-') {
+            let range = Address::parse(first)
+                .filter(same_kind)
+                .zip(Address::parse(last).filter(same_kind))
+                .filter(|(first, last)| AddressPattern::in_range(*first, *last, *first));
+
+            if let Some((first, last)) = range {
+                return Some(AddressMatcher::Range(first, last));
+            }
+        }
+
+        match protocol {
+            Protocol::Ethernet | Protocol::STP => AddressPattern::parse_oui(value).map(AddressMatcher::Oui),
+            _ => None
+        }
+    }
+
+    fn parse_oui(value: &str) -> Option<[u8; 3]> {
+        let octets: Vec<u8> = value
+            .split([':', '-'])
+            .map(|octet| match octet.len() {
+                1 | 2 => u8::from_str_radix(octet, 16).ok(),
+                _ => None
+            })
+            .collect::<Option<Vec<u8>>>()?;
+
+        match octets.as_slice() {
+            [a, b, c] => Some([*a, *b, *c]),
+            _ => None
+        }
+    }
+
+    fn in_subnet(network: Address, prefix_length: u8, address: Address) -> bool {
+        match (network, address) {
+            (Address::IPv4(network), Address::IPv4(address)) => {
+                let mask = u32::MAX.checked_shl(32 - prefix_length as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(address) & mask
+            },
+            (Address::IPv6(network), Address::IPv6(address)) => {
+                let mask = u128::MAX.checked_shl(128 - prefix_length as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(address) & mask
+            },
+            _ => false
+        }
+    }
+
+    fn in_range(first: Address, last: Address, address: Address) -> bool {
+        match (first, last, address) {
+            (Address::IPv4(first), Address::IPv4(last), Address::IPv4(address)) => first <= address && address <= last,
+            (Address::IPv6(first), Address::IPv6(last), Address::IPv6(address)) => first <= address && address <= last,
+            (Address::Port(first), Address::Port(last), Address::Port(address)) => first <= address && address <= last,
+            _ => false
+        }
+    }
+}
+
+impl fmt::Display for AddressPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negated {
+            write!(f, "!")?;
+        }
+
+        match &self.matcher {
+            AddressMatcher::Exact(address) => write!(f, "{}", address),
+            AddressMatcher::Subnet(network, prefix_length) => write!(f, "{}/{}", network, prefix_length),
+            AddressMatcher::Range(first, last) => write!(f, "{}-{}", first, last),
+            AddressMatcher::Oui(oui) => write!(f, "{:02x}:{:02x}:{:02x}", oui[0], oui[1], oui[2]),
+            AddressMatcher::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pnet::util::MacAddr;
+
+    use super::*;
+
+    fn address(value: &str) -> Address {
+        Address::parse(value).unwrap()
+    }
+
+    fn matches(pattern: &str, protocol: Protocol, value: &str) -> bool {
+        AddressPattern::parse(pattern, protocol).unwrap().matches(address(value))
+    }
+
+    #[test]
+    fn matches_ipv4_subnet() {
+        assert!(matches("10.0.0.0/8", Protocol::IPv4, "10.255.3.4"));
+        assert!(!matches("10.0.0.0/8", Protocol::IPv4, "11.0.0.1"));
+    }
+
+    #[test]
+    fn matches_ipv6_subnet() {
+        assert!(matches("fe80::/10", Protocol::IPv6, "fe80::1"));
+        assert!(matches("fe80::/10", Protocol::IPv6, "febf::1"));
+        assert!(!matches("fe80::/10", Protocol::IPv6, "fec0::1"));
+    }
+
+    #[test]
+    fn matches_every_address_with_zero_prefix() {
+        assert!(matches("0.0.0.0/0", Protocol::IPv4, "203.0.113.7"));
+        assert!(matches("::/0", Protocol::IPv6, "2001:db8::1"));
+    }
+
+    #[test]
+    fn matches_single_address_with_full_prefix() {
+        assert!(matches("2001:db8::1/128", Protocol::IPv6, "2001:db8::1"));
+        assert!(!matches("2001:db8::1/128", Protocol::IPv6, "2001:db8::2"));
+        assert!(AddressPattern::parse("2001:db8::1/129", Protocol::IPv6).is_none());
+        assert!(AddressPattern::parse("10.0.0.0/33", Protocol::IPv4).is_none());
+    }
+
+    #[test]
+    fn matches_port_range() {
+        assert!(matches("8000-8100", Protocol::TCP, "8000"));
+        assert!(matches("8000-8100", Protocol::UDP, "8100"));
+        assert!(!matches("8000-8100", Protocol::TCP, "8101"));
+        assert!(!matches("8000-8100", Protocol::TCP, "7999"));
+    }
+
+    #[test]
+    fn rejects_inverted_range() {
+        assert!(AddressPattern::parse("8100-8000", Protocol::TCP).is_none());
+        assert!(AddressPattern::parse("10.0.0.50-10.0.0.1", Protocol::IPv4).is_none());
+    }
+
+    #[test]
+    fn matches_oui_on_ethernet_only() {
+        let pattern = AddressPattern::parse("00:1b:21", Protocol::Ethernet).unwrap();
+
+        assert!(pattern.matches(Address::Mac(MacAddr::new(0x00, 0x1b, 0x21, 0x12, 0x34, 0x56))));
+        assert!(!pattern.matches(Address::Mac(MacAddr::new(0x00, 0x1b, 0x22, 0x12, 0x34, 0x56))));
+        assert!(AddressPattern::parse("00:1b:21", Protocol::IPv4).is_none());
+        assert!(AddressPattern::parse("00:1b:21", Protocol::TCP).is_none());
+    }
+
+    #[test]
+    fn negates_subnet() {
+        let pattern = AddressPattern::parse("!192.168.0.0/16", Protocol::IPv4).unwrap();
+
+        assert!(!pattern.matches(address("192.168.10.1")));
+        assert!(pattern.matches(address("10.0.0.1")));
+        assert_eq!(pattern.to_string(), "!192.168.0.0/16");
+    }
+
+    #[test]
+    fn rejects_addresses_not_carried_by_protocol() {
+        assert!(AddressPattern::parse("10.0.0.0/8", Protocol::IPv6).is_none());
+        assert!(AddressPattern::parse("fe80::1", Protocol::IPv4).is_none());
+    }
+}
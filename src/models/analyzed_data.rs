@@ -1,30 +1,63 @@
+use std::rc::Rc;
+
 use chrono::DateTime;
 use chrono::offset::Local;
 
+use crate::enums::protocol::Protocol;
+use crate::models::dissection_cache::{DissectionCache, Layers};
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+use crate::protocols::reassembled::Reassembled;
 use crate::traits::packet_analysis::PacketAnalysis;
 use crate::traits::packet_filter::PacketFilter;
 use crate::utils::input_util::{read_command, read_input};
 
 pub struct AnalyzedData {
     pub timestamp: DateTime<Local>,
+    pub frame_number: usize,
+    pub captured_length: usize,
+    pub original_length: usize,
+    pub frame: FrameBuffer,
     pub packets: Vec<Box<dyn PacketAnalysis>>,
+    pub reassembled_in: Option<usize>,
+    pub reassembled: Option<Reassembled>,
+    pub dissection_cache: Option<Rc<DissectionCache>>,
 }
 
 impl AnalyzedData {
-    pub fn new() -> AnalyzedData {
+    pub fn new(frame_number: usize, frame: FrameBuffer) -> AnalyzedData {
         AnalyzedData {
             timestamp: Local::now(),
+            frame_number,
+            captured_length: frame.len(),
+            original_length: frame.len(),
+            frame,
             packets: Vec::new(),
+            reassembled_in: None,
+            reassembled: None,
+            dissection_cache: None,
+        }
+    }
+
+    pub fn layers(&self) -> Layers<'_> {
+        match &self.dissection_cache {
+            Some(dissection_cache) => Layers::Cached(dissection_cache.get(self)),
+            None => Layers::Stored(&self.packets)
         }
     }
 
+    pub fn is_malformed(&self) -> bool {
+        self.layers().iter().any(|packet| packet.protocol() == Protocol::Malformed)
+    }
+
     pub fn apply_filters(&self, filters: &Vec<Box<dyn PacketFilter>>) -> bool {
         let mut is_valid = true;
+        let layers = self.layers();
 
         for filter in filters {
             let mut is_filter_valid = false;
 
-            for packet in &self.packets {
+            for packet in layers.iter() {
                 if filter.is_applicable(packet.protocol()) {
                     is_filter_valid = filter.apply(packet);
                     if is_filter_valid {
@@ -42,14 +75,66 @@ impl AnalyzedData {
         is_valid
     }
 
+    /// Fields of layers dissected from a copied buffer (reassembled or concatenated payloads)
+    /// keep offsets relative to that buffer, since they cannot be placed within the frame
+    pub fn get_fields(&self) -> Vec<(Protocol, Vec<PacketField>)> {
+        let mut base = Some(0);
+        let mut layers = Vec::new();
+
+        for packet in self.layers().iter() {
+            let fields = packet.fields()
+                .iter()
+                .map(|field| match base {
+                    Some(base) => field.shifted(base),
+                    None => field.as_relative()
+                })
+                .collect();
+
+            layers.push((packet.protocol(), fields));
+
+            let payload = packet.payload();
+            base = if payload.shares_data(&self.frame) { Some(payload.offset()) } else { None };
+        }
+
+        layers
+    }
+
+    pub fn get_export(&self) -> String {
+        let mut export = format!(
+            "Frame {}: {} bytes ({} captured) at {}\n",
+            self.frame_number,
+            self.original_length,
+            self.captured_length,
+            self.timestamp.format("%Y-%m-%d %H:%M:%S%.6f")
+        );
+
+        for (protocol, fields) in self.get_fields() {
+            export.push_str(format!("{}\n", protocol).as_str());
+
+            for field in fields {
+                export.push_str(field.get_export(1).as_str());
+            }
+        }
+
+        export
+    }
+
     pub fn get_info(&self) -> String {
         let mut description = String::new();
 
-        for packet in &self.packets {
+        for packet in self.layers().iter() {
             description.push_str(packet.short_description().as_str());
             description.push_str(";");
         }
 
+        if let Some(frame_number) = self.reassembled_in {
+            description.push_str(format!(" [reassembled in #{}]", frame_number).as_str());
+        }
+
+        if self.is_malformed() {
+            return format!("[{}] [MALFORMED] {}", self.timestamp.format("%Y-%m-%d %H:%M:%S"), description);
+        }
+
         format!("[{}] {}", self.timestamp.format("%Y-%m-%d %H:%M:%S"), description)
     }
 
@@ -58,6 +143,11 @@ impl AnalyzedData {
         self.print_instructions();
         println!("|");
         println!("| Timestamp: {}", self.timestamp.format("%Y-%m-%d %H:%M:%S"));
+        println!("| Frame number: {}", self.frame_number);
+        println!("| Frame length: {} bytes ({} captured)", self.original_length, self.captured_length);
+        if let Some(frame_number) = self.reassembled_in {
+            println!("| Reassembled in frame: #{}", frame_number);
+        }
         self.print_collected_packets();
         println!("|");
 
@@ -67,7 +157,7 @@ impl AnalyzedData {
     fn print_collected_packets(&self) {
         println!("--------- Collected packets ---------");
         println!("|");
-        for (idx, packet) in self.packets.iter().enumerate() {
+        for (idx, packet) in self.layers().iter().enumerate() {
             println!("| [{}]: {}", idx, packet.short_description());
         }
     }
@@ -75,8 +165,9 @@ impl AnalyzedData {
     fn print_packet_payload(&self) {
         let packet_idx: Option<usize> = read_input("Packet index");
 
+        let layers = self.layers();
         let packet = match packet_idx {
-            Some(idx) => self.packets.get(idx),
+            Some(idx) => layers.get(idx),
             None => None
         };
 
@@ -129,9 +220,59 @@ impl AnalyzedData {
             }
         };
 
-        match self.packets.get(packet_idx) {
+        match self.layers().get(packet_idx) {
             Some(packet) => packet.print_details(),
             None => println!("Packet with index {} does not exist", packet_idx)
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use pnet::packet::ip::IpNextHeaderProtocol;
+
+    use crate::protocols::udp::UDP;
+
+    use super::*;
+
+    const UDP_DATAGRAM: [u8; 13] = [0x14, 0xe9, 0x14, 0xe9, 0x00, 0x0d, 0x10, 0x17, b'h', b'e', b'l', b'l', b'o'];
+
+    fn field(fields: &[(Protocol, Vec<PacketField>)], protocol: Protocol, name: &str) -> PacketField {
+        fields
+            .iter()
+            .filter(|(layer, _)| *layer == protocol)
+            .flat_map(|(_, fields)| fields.iter().flat_map(|field| field.find(name)))
+            .next()
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn shifts_fields_of_layers_within_the_frame() {
+        let mut frame = vec![0u8; 20];
+        frame.extend_from_slice(&UDP_DATAGRAM);
+
+        let mut analyzed_data = AnalyzedData::new(1, FrameBuffer::from(frame));
+        let datagram = analyzed_data.frame.slice(20..);
+        analyzed_data.packets.push(Reassembled::new(Protocol::IPv4, IpNextHeaderProtocol(17), vec![1], false, datagram.clone()));
+        analyzed_data.packets.push(UDP::new(&datagram).unwrap());
+
+        let length = field(&analyzed_data.get_fields(), Protocol::UDP, "udp.length");
+
+        assert_eq!((length.offset, length.relative), (24, false));
+    }
+
+    #[test]
+    fn keeps_offsets_of_copied_payloads_relative() {
+        let mut analyzed_data = AnalyzedData::new(2, FrameBuffer::from(vec![0u8; 28]));
+        let datagram = FrameBuffer::from(UDP_DATAGRAM.to_vec());
+        analyzed_data.packets.push(Reassembled::new(Protocol::IPv4, IpNextHeaderProtocol(17), vec![1, 2], false, datagram.clone()));
+        analyzed_data.packets.push(UDP::new(&datagram).unwrap());
+
+        let fields = analyzed_data.get_fields();
+        let length = field(&fields, Protocol::UDP, "udp.length");
+
+        assert_eq!((length.offset, length.relative), (4, true));
+        assert!(length.get_export(0).contains("relative offset 4 length 2"));
+        assert!(!field(&fields, Protocol::Reassembled, "reassembled.length").relative);
+    }
+}
@@ -3,7 +3,13 @@ use crate::enums::protocol::Protocol;
 
 pub struct AnalyzedTraffic {
     protocol_usages: HashMap<Protocol, usize>,
+    mpls_label_usages: HashMap<u32, usize>,
+    topology_changes: Vec<String>,
     total_packets: usize,
+    multicast_packets: usize,
+    broadcast_packets: usize,
+    invalid_checksum_packets: usize,
+    malformed_packets: usize,
     sniffing_duration: u64,
     average_packet_size: usize,
     average_packets_per_second: f64,
@@ -23,7 +29,13 @@ impl AnalyzedTraffic {
     ) -> AnalyzedTraffic {
         AnalyzedTraffic {
             protocol_usages,
+            mpls_label_usages: HashMap::new(),
+            topology_changes: Vec::new(),
             total_packets,
+            multicast_packets: 0,
+            broadcast_packets: 0,
+            invalid_checksum_packets: 0,
+            malformed_packets: 0,
             sniffing_duration,
             average_packet_size,
             average_packets_per_second,
@@ -32,23 +44,65 @@ impl AnalyzedTraffic {
         }
     }
 
+    pub fn with_mpls_label_usages(mut self, mpls_label_usages: HashMap<u32, usize>) -> AnalyzedTraffic {
+        self.mpls_label_usages = mpls_label_usages;
+        self
+    }
+
+    pub fn with_topology_changes(mut self, topology_changes: Vec<String>) -> AnalyzedTraffic {
+        self.topology_changes = topology_changes;
+        self
+    }
+
+    pub fn with_multicast_packets(mut self, multicast_packets: usize) -> AnalyzedTraffic {
+        self.multicast_packets = multicast_packets;
+        self
+    }
+
+    pub fn with_broadcast_packets(mut self, broadcast_packets: usize) -> AnalyzedTraffic {
+        self.broadcast_packets = broadcast_packets;
+        self
+    }
+
+    pub fn with_invalid_checksum_packets(mut self, invalid_checksum_packets: usize) -> AnalyzedTraffic {
+        self.invalid_checksum_packets = invalid_checksum_packets;
+        self
+    }
+
+    pub fn with_malformed_packets(mut self, malformed_packets: usize) -> AnalyzedTraffic {
+        self.malformed_packets = malformed_packets;
+        self
+    }
+
     pub fn get_info(&self) -> String {
         format!(
             "Total packets: {}\n\
+            Multicast packets: {}\n\
+            Broadcast packets: {}\n\
+            Packets with invalid checksums: {}\n\
+            Malformed packets: {}\n\
             Sniffing duration: {} seconds\n\
             Average packet size: {} bytes\n\
             Average packets per second: {}\n\
             Max packet size: {} bytes\n\
             Min packet size: {} bytes\n\
             Protocol usages:\n\
+            {}\
+            {}\
             {}",
             self.total_packets,
+            self.multicast_packets,
+            self.broadcast_packets,
+            self.invalid_checksum_packets,
+            self.malformed_packets,
             self.sniffing_duration,
             self.average_packet_size,
             self.average_packets_per_second,
             self.max_packet_size,
             self.min_packet_size,
             self.get_protocol_usages_formatted(),
+            self.get_mpls_label_usages_formatted(),
+            self.get_topology_changes_formatted(),
         )
     }
 
@@ -61,4 +115,35 @@ impl AnalyzedTraffic {
 
         formatted
     }
+
+    fn get_mpls_label_usages_formatted(&self) -> String {
+        if self.mpls_label_usages.is_empty() {
+            return String::new();
+        }
+
+        let mut labels: Vec<(&u32, &usize)> = self.mpls_label_usages.iter().collect();
+        labels.sort();
+
+        let mut formatted = String::from("MPLS label usages:\n");
+
+        for (label, usage) in labels {
+            formatted.push_str(format!("\tLabel {}: {}\n", label, usage).as_str());
+        }
+
+        formatted
+    }
+
+    fn get_topology_changes_formatted(&self) -> String {
+        if self.topology_changes.is_empty() {
+            return String::new();
+        }
+
+        let mut formatted = format!("Topology changes: {}\n", self.topology_changes.len());
+
+        for topology_change in &self.topology_changes {
+            formatted.push_str(format!("\t{}\n", topology_change).as_str());
+        }
+
+        formatted
+    }
 }
\ No newline at end of file
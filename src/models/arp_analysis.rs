@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::net::Ipv4Addr;
+
+use pnet::util::MacAddr;
+
+pub struct ArpAnalysis {
+    bindings: HashMap<Ipv4Addr, Vec<MacAddr>>,
+    mac_changes: Vec<String>,
+    unsolicited_replies: Vec<String>,
+}
+
+impl ArpAnalysis {
+    pub fn new() -> ArpAnalysis {
+        ArpAnalysis {
+            bindings: HashMap::new(),
+            mac_changes: Vec::new(),
+            unsolicited_replies: Vec::new(),
+        }
+    }
+
+    pub fn observe_binding(&mut self, ip: Ipv4Addr, mac: MacAddr, frame_number: usize) {
+        let macs = self.bindings.entry(ip).or_default();
+
+        if let Some(current_mac) = macs.last() {
+            if *current_mac != mac {
+                self.mac_changes.push(format!(
+                    "#{}: {} changed from {} to {}",
+                    frame_number,
+                    ip,
+                    current_mac,
+                    mac
+                ));
+            }
+        }
+
+        macs.retain(|known_mac| *known_mac != mac);
+        macs.push(mac);
+    }
+
+    pub fn add_unsolicited_reply(&mut self, ip: Ipv4Addr, mac: MacAddr, frame_number: usize) {
+        self.unsolicited_replies.push(format!(
+            "#{}: {} is at {} without a matching request",
+            frame_number,
+            ip,
+            mac
+        ));
+    }
+
+    pub fn get_info(&self) -> String {
+        format!(
+            "IP to MAC table:\n\
+            {}\
+            Conflicts:\n\
+            {}\
+            MAC changes:\n\
+            {}\
+            Unsolicited replies:\n\
+            {}",
+            self.get_table_formatted(),
+            self.get_conflicts_formatted(),
+            ArpAnalysis::get_list_formatted(&self.mac_changes),
+            ArpAnalysis::get_list_formatted(&self.unsolicited_replies),
+        )
+    }
+
+    fn get_sorted_bindings(&self) -> Vec<(&Ipv4Addr, &Vec<MacAddr>)> {
+        let mut bindings: Vec<(&Ipv4Addr, &Vec<MacAddr>)> = self.bindings.iter().collect();
+        bindings.sort_by_key(|(ip, _)| **ip);
+
+        bindings
+    }
+
+    fn get_table_formatted(&self) -> String {
+        let mut formatted = String::new();
+
+        for (ip, macs) in self.get_sorted_bindings() {
+            if let Some(mac) = macs.last() {
+                formatted.push_str(format!("\t{} -> {}\n", ip, mac).as_str());
+            }
+        }
+
+        if formatted.is_empty() {
+            formatted.push_str("\tnone\n");
+        }
+
+        formatted
+    }
+
+    fn get_conflicts_formatted(&self) -> String {
+        let mut formatted = String::new();
+
+        for (ip, macs) in self.get_sorted_bindings() {
+            if macs.len() > 1 {
+                let macs: Vec<String> = macs.iter().map(|mac| mac.to_string()).collect();
+                formatted.push_str(format!("\t{} claimed by {} (possible spoofing)\n", ip, macs.join(", ")).as_str());
+            }
+        }
+
+        if formatted.is_empty() {
+            formatted.push_str("\tnone\n");
+        }
+
+        formatted
+    }
+
+    fn get_list_formatted(entries: &Vec<String>) -> String {
+        if entries.is_empty() {
+            return String::from("\tnone\n");
+        }
+
+        let mut formatted = String::new();
+
+        for entry in entries {
+            formatted.push_str(format!("\t{}\n", entry).as_str());
+        }
+
+        formatted
+    }
+}
@@ -0,0 +1,95 @@
+use std::net::IpAddr;
+
+use strum::IntoEnumIterator;
+
+use crate::enums::protocol::Protocol;
+
+#[derive(Clone, PartialEq)]
+pub struct DecodeAsRule {
+    pub transport: Protocol,
+    pub address: Option<IpAddr>,
+    pub first_port: u16,
+    pub last_port: u16,
+    pub protocol: Protocol,
+}
+
+impl DecodeAsRule {
+    pub fn matches(&self, transport: Protocol, addresses: &[IpAddr], ports: &[u16]) -> bool {
+        if transport != self.transport {
+            return false;
+        }
+
+        let port_matches = |port: &u16| *port >= self.first_port && *port <= self.last_port;
+
+        match self.address {
+            Some(address) => addresses
+                .iter()
+                .zip(ports.iter())
+                .any(|(packet_address, port)| *packet_address == address && port_matches(port)),
+            None => ports.iter().any(port_matches)
+        }
+    }
+
+    pub fn parse(line: &str) -> Option<DecodeAsRule> {
+        let mut parts = line.split_whitespace();
+
+        let transport = match parts.next()?.to_uppercase().as_str() {
+            "TCP" => Protocol::TCP,
+            "UDP" => Protocol::UDP,
+            _ => return None
+        };
+
+        let target = parts.next()?;
+        let protocol_name = parts.next()?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let protocol = Protocol::iter().find(|protocol| protocol.to_string().eq_ignore_ascii_case(protocol_name))?;
+
+        let (address, ports) = match target.rsplit_once(':') {
+            Some((address, ports)) => {
+                let address = address.trim_start_matches('[').trim_end_matches(']');
+                (Some(address.parse::<IpAddr>().ok()?), ports)
+            },
+            None => (None, target)
+        };
+
+        let (first_port, last_port) = match ports.split_once('-') {
+            Some((first, last)) => (first.parse::<u16>().ok()?, last.parse::<u16>().ok()?),
+            None => {
+                let port = ports.parse::<u16>().ok()?;
+                (port, port)
+            }
+        };
+
+        if first_port > last_port {
+            return None;
+        }
+
+        Some(DecodeAsRule {
+            transport,
+            address,
+            first_port,
+            last_port,
+            protocol,
+        })
+    }
+
+    pub fn get_config(&self) -> String {
+        let ports = if self.first_port == self.last_port {
+            format!("{}", self.first_port)
+        } else {
+            format!("{}-{}", self.first_port, self.last_port)
+        };
+
+        let target = match self.address {
+            Some(IpAddr::V6(address)) => format!("[{}]:{}", address, ports),
+            Some(address) => format!("{}:{}", address, ports),
+            None => ports
+        };
+
+        format!("{} {} {}", self.transport, target, self.protocol)
+    }
+}
@@ -0,0 +1,73 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::models::analyzed_data::AnalyzedData;
+use crate::traits::frame_dissector::FrameDissector;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+type CachedLayers = Rc<Vec<Box<dyn PacketAnalysis>>>;
+
+pub enum Layers<'a> {
+    Stored(&'a [Box<dyn PacketAnalysis>]),
+    Cached(CachedLayers),
+}
+
+impl Deref for Layers<'_> {
+    type Target = [Box<dyn PacketAnalysis>];
+
+    fn deref(&self) -> &[Box<dyn PacketAnalysis>] {
+        match self {
+            Layers::Stored(layers) => layers,
+            Layers::Cached(layers) => layers.as_slice()
+        }
+    }
+}
+
+/// Materializes the layers of lazily captured frames, keeping the most recently used ones.
+pub struct DissectionCache {
+    dissector: RefCell<Box<dyn FrameDissector>>,
+    entries: RefCell<VecDeque<(usize, CachedLayers)>>,
+}
+
+impl DissectionCache {
+    const CAPACITY: usize = 64;
+
+    pub fn new(dissector: Box<dyn FrameDissector>) -> DissectionCache {
+        DissectionCache {
+            dissector: RefCell::new(dissector),
+            entries: RefCell::new(VecDeque::with_capacity(DissectionCache::CAPACITY)),
+        }
+    }
+
+    pub fn get(&self, analyzed_data: &AnalyzedData) -> CachedLayers {
+        let mut entries = self.entries.borrow_mut();
+
+        if let Some(position) = entries.iter().position(|(frame_number, _)| *frame_number == analyzed_data.frame_number) {
+            let entry = entries.remove(position).unwrap();
+            let layers = entry.1.clone();
+            entries.push_back(entry);
+
+            return layers;
+        }
+
+        let layers = Rc::new(self.dissector.borrow().dissect_frame(analyzed_data));
+
+        if entries.len() >= DissectionCache::CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back((analyzed_data.frame_number, layers.clone()));
+
+        layers
+    }
+
+    pub fn set_dissector(&self, dissector: Box<dyn FrameDissector>) {
+        *self.dissector.borrow_mut() = dissector;
+        self.clear();
+    }
+
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
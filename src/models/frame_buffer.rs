@@ -0,0 +1,98 @@
+use std::fmt;
+use std::ops::{Bound, Deref, RangeBounds};
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub struct FrameBuffer {
+    data: Rc<[u8]>,
+    offset: usize,
+    length: usize,
+}
+
+impl FrameBuffer {
+    pub fn new(data: &[u8]) -> FrameBuffer {
+        FrameBuffer::from(data.to_vec())
+    }
+
+    /// Joins the fragments into one buffer. A single fragment is shared as is, while several
+    /// are copied into a new buffer that no longer maps onto the frame they came from
+    pub fn concat(fragments: Vec<FrameBuffer>) -> FrameBuffer {
+        match fragments.len() {
+            0 => FrameBuffer::default(),
+            1 => fragments.into_iter().next().unwrap_or_default(),
+            _ => FrameBuffer::from(fragments.iter().flat_map(|fragment| fragment.iter().copied()).collect::<Vec<u8>>())
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Whether both buffers are views of the same underlying data, so their offsets are comparable
+    pub fn shares_data(&self, other: &FrameBuffer) -> bool {
+        Rc::ptr_eq(&self.data, &other.data)
+    }
+
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> FrameBuffer {
+        match self.checked_slice(range) {
+            Some(buffer) => buffer,
+            None => panic!("slice out of range for frame buffer of length {}", self.length)
+        }
+    }
+
+    pub fn checked_slice(&self, range: impl RangeBounds<usize>) -> Option<FrameBuffer> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start + 1,
+            Bound::Unbounded => 0
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(end) => end + 1,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.length
+        };
+
+        if start > end || end > self.length {
+            return None;
+        }
+
+        Some(FrameBuffer {
+            data: self.data.clone(),
+            offset: self.offset + start,
+            length: end - start,
+        })
+    }
+}
+
+impl Default for FrameBuffer {
+    fn default() -> FrameBuffer {
+        FrameBuffer::from(Vec::new())
+    }
+}
+
+impl From<Vec<u8>> for FrameBuffer {
+    fn from(data: Vec<u8>) -> FrameBuffer {
+        let length = data.len();
+
+        FrameBuffer {
+            data: Rc::from(data),
+            offset: 0,
+            length,
+        }
+    }
+}
+
+impl Deref for FrameBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.data[self.offset..self.offset + self.length]
+    }
+}
+
+impl fmt::Debug for FrameBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.deref(), f)
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::Ipv4Addr;
+
+use chrono::DateTime;
+use chrono::offset::Local;
+
+pub struct IgmpAnalysis {
+    memberships: BTreeMap<Ipv4Addr, BTreeSet<String>>,
+    events: Vec<String>,
+}
+
+impl IgmpAnalysis {
+    pub fn new() -> IgmpAnalysis {
+        IgmpAnalysis {
+            memberships: BTreeMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record_membership(&mut self, frame_number: usize, timestamp: DateTime<Local>, host: String, group: Ipv4Addr, joined: bool) {
+        let members = self.memberships.entry(group).or_default();
+
+        if joined {
+            members.insert(host.clone());
+        } else {
+            members.remove(&host);
+        }
+
+        self.events.push(format!(
+            "#{} {}: {} {} {}",
+            frame_number,
+            timestamp.format("%H:%M:%S"),
+            host,
+            if joined { "joined" } else { "left" },
+            group
+        ));
+    }
+
+    pub fn get_info(&self) -> String {
+        format!(
+            "Current group memberships:\n\
+            {}\
+            Membership changes:\n\
+            {}",
+            self.get_memberships_formatted(),
+            self.get_events_formatted(),
+        )
+    }
+
+    fn get_memberships_formatted(&self) -> String {
+        let mut formatted = String::new();
+
+        for (group, members) in &self.memberships {
+            let members: Vec<&str> = members.iter().map(|member| member.as_str()).collect();
+
+            if members.is_empty() {
+                formatted.push_str(format!("\t{}: no members\n", group).as_str());
+            } else {
+                formatted.push_str(format!("\t{}: {}\n", group, members.join(", ")).as_str());
+            }
+        }
+
+        if formatted.is_empty() {
+            formatted.push_str("\tnone\n");
+        }
+
+        formatted
+    }
+
+    fn get_events_formatted(&self) -> String {
+        if self.events.is_empty() {
+            return String::from("\tnone\n");
+        }
+
+        let mut formatted = String::new();
+
+        for event in &self.events {
+            formatted.push_str(format!("\t{}\n", event).as_str());
+        }
+
+        formatted
+    }
+}
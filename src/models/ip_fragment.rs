@@ -0,0 +1,21 @@
+use pnet::packet::ip::IpNextHeaderProtocol;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct FragmentKey {
+    pub ip_version: Protocol,
+    pub source: String,
+    pub destination: String,
+    pub identification: u32,
+    pub protocol: u8,
+}
+
+pub struct IpFragment {
+    pub identification: u32,
+    pub offset: usize,
+    pub more_fragments: bool,
+    pub next_protocol: IpNextHeaderProtocol,
+    pub data: FrameBuffer,
+}
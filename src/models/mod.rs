@@ -1,2 +1,12 @@
 pub(crate) mod analyzed_data;
-pub(crate) mod analyzed_traffic;
\ No newline at end of file
+pub(crate) mod analyzed_traffic;
+pub(crate) mod ip_fragment;
+pub(crate) mod arp_analysis;
+pub(crate) mod neighbor;
+pub(crate) mod ntp_analysis;
+pub(crate) mod igmp_analysis;
+pub(crate) mod decode_as_rule;
+pub(crate) mod packet_field;
+pub(crate) mod frame_buffer;
+pub(crate) mod dissection_cache;
+pub(crate) mod address_pattern;
\ No newline at end of file
@@ -0,0 +1,60 @@
+use crate::enums::protocol::Protocol;
+
+#[derive(Clone)]
+pub struct Neighbor {
+    pub protocol: Protocol,
+    pub device_id: String,
+    pub port_id: String,
+    pub system_name: Option<String>,
+    pub system_description: Option<String>,
+    pub platform: Option<String>,
+    pub management_addresses: Vec<String>,
+    pub vlan: Option<u16>,
+    pub capabilities: Vec<String>,
+}
+
+impl Neighbor {
+    pub fn new(protocol: Protocol, device_id: String, port_id: String) -> Neighbor {
+        Neighbor {
+            protocol,
+            device_id,
+            port_id,
+            system_name: None,
+            system_description: None,
+            platform: None,
+            management_addresses: Vec::new(),
+            vlan: None,
+            capabilities: Vec::new(),
+        }
+    }
+
+    pub fn get_info(&self) -> String {
+        format!(
+            "{} neighbor {}\n\
+            \tPort: {}\n\
+            \tSystem name: {}\n\
+            \tSystem description: {}\n\
+            \tPlatform: {}\n\
+            \tManagement addresses: {}\n\
+            \tVLAN: {}\n\
+            \tCapabilities: {}\n",
+            self.protocol,
+            self.device_id,
+            self.port_id,
+            self.system_name.as_deref().unwrap_or("unknown"),
+            self.system_description.as_deref().unwrap_or("unknown"),
+            self.platform.as_deref().unwrap_or("unknown"),
+            Neighbor::get_list_formatted(&self.management_addresses),
+            self.vlan.map(|vlan| vlan.to_string()).unwrap_or(String::from("unknown")),
+            Neighbor::get_list_formatted(&self.capabilities),
+        )
+    }
+
+    fn get_list_formatted(entries: &[String]) -> String {
+        if entries.is_empty() {
+            return String::from("unknown");
+        }
+
+        entries.join(", ")
+    }
+}
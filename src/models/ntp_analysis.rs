@@ -0,0 +1,56 @@
+pub struct NtpExchange {
+    pub request_frame: usize,
+    pub response_frame: usize,
+    pub client: String,
+    pub server: String,
+    pub stratum: u8,
+    pub delay: f64,
+    pub offset: f64,
+}
+
+pub struct NtpAnalysis {
+    exchanges: Vec<NtpExchange>,
+    unanswered_requests: usize,
+}
+
+impl NtpAnalysis {
+    const OFFSET_WARNING_SECONDS: f64 = 0.1;
+
+    pub fn new(exchanges: Vec<NtpExchange>, unanswered_requests: usize) -> NtpAnalysis {
+        NtpAnalysis {
+            exchanges,
+            unanswered_requests,
+        }
+    }
+
+    pub fn get_info(&self) -> String {
+        format!(
+            "Matched exchanges: {}\n\
+            Unanswered requests: {}\n\
+            {}",
+            self.exchanges.len(),
+            self.unanswered_requests,
+            self.get_exchanges_formatted(),
+        )
+    }
+
+    fn get_exchanges_formatted(&self) -> String {
+        let mut formatted = String::new();
+
+        for exchange in &self.exchanges {
+            formatted.push_str(format!(
+                "\t#{} -> #{}: {} -> {} stratum {} delay {:.3} ms offset {:+.3} ms{}\n",
+                exchange.request_frame,
+                exchange.response_frame,
+                exchange.client,
+                exchange.server,
+                exchange.stratum,
+                exchange.delay * 1000.0,
+                exchange.offset * 1000.0,
+                if exchange.offset.abs() > NtpAnalysis::OFFSET_WARNING_SECONDS { " (clock out of sync)" } else { "" }
+            ).as_str());
+        }
+
+        formatted
+    }
+}
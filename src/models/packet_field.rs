@@ -0,0 +1,296 @@
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use pnet::util::MacAddr;
+
+#[derive(Clone, PartialEq)]
+pub enum FieldValue {
+    None,
+    Bool(bool),
+    Unsigned(u64),
+    Hex(u64, usize),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+    Mac(MacAddr),
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+}
+
+impl FieldValue {
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            FieldValue::None => expected.is_empty(),
+            FieldValue::Bool(value) => expected.parse::<bool>().is_ok_and(|expected| expected == *value),
+            FieldValue::Unsigned(value) | FieldValue::Hex(value, _) => FieldValue::parse_unsigned(expected) == Some(*value),
+            FieldValue::Float(value) => expected.parse::<f64>().is_ok_and(|expected| expected == *value),
+            FieldValue::Text(value) => value.eq_ignore_ascii_case(expected),
+            FieldValue::Bytes(value) => FieldValue::parse_bytes(expected).as_ref() == Some(value),
+            FieldValue::Mac(value) => expected.parse::<MacAddr>().is_ok_and(|expected| expected == *value),
+            FieldValue::Ipv4(value) => expected.parse::<Ipv4Addr>().is_ok_and(|expected| expected == *value),
+            FieldValue::Ipv6(value) => expected.parse::<Ipv6Addr>().is_ok_and(|expected| expected == *value),
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            FieldValue::Unsigned(value) | FieldValue::Hex(value, _) => Some(*value as f64),
+            FieldValue::Float(value) => Some(*value),
+            _ => None
+        }
+    }
+
+    pub fn parse_number(value: &str) -> Option<f64> {
+        FieldValue::parse_unsigned(value)
+            .map(|value| value as f64)
+            .or_else(|| value.parse::<f64>().ok())
+    }
+
+    fn parse_unsigned(value: &str) -> Option<u64> {
+        match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => value.parse::<u64>().ok()
+        }
+    }
+
+    pub fn parse_bytes(value: &str) -> Option<Vec<u8>> {
+        let hex: String = value
+            .trim_start_matches("0x")
+            .chars()
+            .filter(|character| *character != ':' && *character != ' ')
+            .collect();
+
+        if !hex.len().is_multiple_of(2) {
+            return None;
+        }
+
+        (0..hex.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(hex.get(idx..idx + 2)?, 16).ok())
+            .collect()
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldValue::None => Ok(()),
+            FieldValue::Bool(value) => write!(f, "{}", value),
+            FieldValue::Unsigned(value) => write!(f, "{}", value),
+            FieldValue::Hex(value, width) => write!(f, "0x{:0width$x}", value, width = width),
+            FieldValue::Float(value) => write!(f, "{}", value),
+            FieldValue::Text(value) => write!(f, "{}", value),
+            FieldValue::Bytes(value) => {
+                let bytes: Vec<String> = value.iter().map(|byte| format!("{:02x}", byte)).collect();
+                write!(f, "{}", bytes.join(":"))
+            },
+            FieldValue::Mac(value) => write!(f, "{}", value),
+            FieldValue::Ipv4(value) => write!(f, "{}", value),
+            FieldValue::Ipv6(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! unsigned_field_value {
+    ($($source:ty),*) => {
+        $(
+            impl From<$source> for FieldValue {
+                fn from(value: $source) -> FieldValue {
+                    FieldValue::Unsigned(value as u64)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_field_value!(u8, u16, u32, u64, usize);
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> FieldValue {
+        FieldValue::Bool(value)
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> FieldValue {
+        FieldValue::Float(value)
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> FieldValue {
+        FieldValue::Text(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> FieldValue {
+        FieldValue::Text(value.to_string())
+    }
+}
+
+impl From<&[u8]> for FieldValue {
+    fn from(value: &[u8]) -> FieldValue {
+        FieldValue::Bytes(value.to_vec())
+    }
+}
+
+impl From<MacAddr> for FieldValue {
+    fn from(value: MacAddr) -> FieldValue {
+        FieldValue::Mac(value)
+    }
+}
+
+impl From<Ipv4Addr> for FieldValue {
+    fn from(value: Ipv4Addr) -> FieldValue {
+        FieldValue::Ipv4(value)
+    }
+}
+
+impl From<Ipv6Addr> for FieldValue {
+    fn from(value: Ipv6Addr) -> FieldValue {
+        FieldValue::Ipv6(value)
+    }
+}
+
+impl From<IpAddr> for FieldValue {
+    fn from(value: IpAddr) -> FieldValue {
+        match value {
+            IpAddr::V4(address) => FieldValue::Ipv4(address),
+            IpAddr::V6(address) => FieldValue::Ipv6(address),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PacketField {
+    pub name: String,
+    pub label: String,
+    pub value: FieldValue,
+    pub meaning: Option<String>,
+    pub offset: usize,
+    pub length: usize,
+    pub relative: bool,
+    pub children: Vec<PacketField>,
+}
+
+impl PacketField {
+    pub fn new(name: &str, label: &str, value: impl Into<FieldValue>, offset: usize, length: usize) -> PacketField {
+        PacketField {
+            name: name.to_string(),
+            label: label.to_string(),
+            value: value.into(),
+            meaning: None,
+            offset,
+            length,
+            relative: false,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn generated(name: &str, label: &str, value: impl Into<FieldValue>) -> PacketField {
+        PacketField::new(name, label, value, 0, 0)
+    }
+
+    pub fn hex(name: &str, label: &str, value: impl Into<u64>, offset: usize, length: usize) -> PacketField {
+        PacketField::new(name, label, FieldValue::Hex(value.into(), length * 2), offset, length)
+    }
+
+    pub fn group(name: &str, label: &str, offset: usize, length: usize, children: Vec<PacketField>) -> PacketField {
+        PacketField::new(name, label, FieldValue::None, offset, length).with_children(children)
+    }
+
+    pub fn with_meaning(mut self, meaning: impl ToString) -> PacketField {
+        self.meaning = Some(meaning.to_string());
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<PacketField>) -> PacketField {
+        self.children = children;
+        self
+    }
+
+    pub fn is_generated(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn get_display(&self) -> String {
+        match (&self.value, &self.meaning) {
+            (FieldValue::None, Some(meaning)) => meaning.clone(),
+            (value, Some(meaning)) => format!("{} ({})", value, meaning),
+            (value, None) => format!("{}", value),
+        }
+    }
+
+    pub fn shifted(&self, base: usize) -> PacketField {
+        self.mapped(&|offset| offset + base)
+    }
+
+    /// Marks the field as located in a buffer other than the frame, such as a reassembled datagram
+    pub fn as_relative(&self) -> PacketField {
+        let mut field = self.clone();
+
+        field.relative = !field.is_generated();
+        field.children = self.children.iter().map(PacketField::as_relative).collect();
+        field
+    }
+
+    pub fn mapped(&self, map: &dyn Fn(usize) -> usize) -> PacketField {
+        let mut field = self.clone();
+
+        if !field.is_generated() {
+            field.offset = map(field.offset);
+        }
+
+        field.children = self.children.iter().map(|child| child.mapped(map)).collect();
+        field
+    }
+
+    pub fn find(&self, name: &str) -> Vec<&PacketField> {
+        let mut found = Vec::new();
+
+        if self.name == name {
+            found.push(self);
+        }
+
+        for child in &self.children {
+            found.extend(child.find(name));
+        }
+
+        found
+    }
+
+    pub fn print(&self, depth: usize) {
+        let indent = "  ".repeat(depth);
+
+        match (&self.value, &self.meaning) {
+            (FieldValue::None, None) => println!("| {}{}:", indent, self.label),
+            _ => println!("| {}{}: {}", indent, self.label, self.get_display()),
+        }
+
+        for child in &self.children {
+            child.print(depth + 1);
+        }
+    }
+
+    pub fn get_export(&self, depth: usize) -> String {
+        let location = if self.is_generated() {
+            String::from("generated")
+        } else if self.relative {
+            format!("relative offset {} length {}", self.offset, self.length)
+        } else {
+            format!("offset {} length {}", self.offset, self.length)
+        };
+
+        let mut export = match (&self.value, &self.meaning) {
+            (FieldValue::None, None) => format!("{}{} [{}; {}]\n", "\t".repeat(depth), self.label, self.name, location),
+            _ => format!("{}{} = {} [{}; {}]\n", "\t".repeat(depth), self.label, self.get_display(), self.name, location)
+        };
+
+        for child in &self.children {
+            export.push_str(child.get_export(depth + 1).as_str());
+        }
+
+        export
+    }
+}
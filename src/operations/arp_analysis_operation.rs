@@ -0,0 +1,43 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+
+pub struct ArpAnalysisOperation {}
+
+impl ArpAnalysisOperation {
+    const OPERATION: ArpAnalysisOperation = ArpAnalysisOperation {};
+
+    pub fn new() -> ArpAnalysisOperation {
+        ArpAnalysisOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for ArpAnalysisOperation {
+    fn run(&self, runner: &mut Runner) {
+        let arp_analysis = runner.sniffer.get_arp_analysis();
+        println!("{}", arp_analysis.get_info());
+        println!("|");
+    }
+
+    fn get_description(&self) -> String {
+        String::from("ARP spoofing analysis")
+    }
+
+    fn print_instructions(&self) {
+        println!("------------ ARP Spoofing Analysis -----------");
+        println!("|");
+    }
+
+    fn verify_prerequisites(&self, runner: &Runner) -> bool {
+        let has_sniffing_data = !runner.sniffer.get_sniffed_packets().is_empty();
+
+        if !has_sniffing_data {
+            println!("\n------------ ERROR -----------");
+            println!("|");
+            println!("| No sniffing data available, cannot perform ARP analysis!");
+            println!("| Please start sniffing first");
+            println!("|\n");
+        }
+
+        has_sniffing_data
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+
+pub struct ChecksumOffloadOperation {}
+
+impl ChecksumOffloadOperation {
+    const OPERATION: ChecksumOffloadOperation = ChecksumOffloadOperation {};
+
+    pub fn new() -> ChecksumOffloadOperation {
+        ChecksumOffloadOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for ChecksumOffloadOperation {
+    fn run(&self, runner: &mut Runner) {
+        let checksum_offload = !runner.sniffer.is_checksum_offload();
+        runner.sniffer.set_checksum_offload(checksum_offload);
+
+        println!("------- Checksum offload -------");
+        println!("|");
+        if checksum_offload {
+            println!("| Checksum offload enabled, IPv4, TCP and UDP checksums are not verified");
+        } else {
+            println!("| Checksum offload disabled, IPv4, TCP and UDP checksums are verified");
+        }
+        println!("|\n");
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Toggle checksum offload")
+    }
+
+    fn print_instructions(&self) {
+        println!("\n------- Checksum offload -------");
+        println!("|");
+        println!("| Enable on hosts where the network card computes checksums,");
+        println!("| otherwise outgoing packets are reported with incorrect checksums");
+        println!("|\n");
+    }
+
+    fn verify_prerequisites(&self, _runner: &Runner) -> bool {
+        true
+    }
+}
@@ -0,0 +1,167 @@
+use crate::models::decode_as_rule::DecodeAsRule;
+use crate::services::dissector_registry::DissectorRegistry;
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+use crate::utils::index_util::select_index;
+use crate::utils::input_util::{read_command, read_input};
+
+pub struct DecodeAsOperation {}
+
+impl DecodeAsOperation {
+    const OPERATION: DecodeAsOperation = DecodeAsOperation {};
+
+    pub fn new() -> DecodeAsOperation {
+        DecodeAsOperation::OPERATION
+    }
+
+    fn print_current_rules(&self, runner: &Runner) {
+        let rules = runner.decode_as_controller.get_rules();
+
+        println!("------ Decode as rules ------");
+        println!("|");
+
+        for (idx, rule) in rules.iter().enumerate() {
+            println!("| [{}] {}", idx, rule.get_config());
+        }
+
+        println!("|");
+    }
+
+    fn add_rule(&self, runner: &mut Runner) {
+        println!("------ Add decode as rule ------");
+        println!("|");
+        println!("| Enter the transport and the port, port range or address with port to match");
+        println!("| e.g. 'tcp 8081', 'udp 5000-5010' or 'tcp 10.0.0.5:8443'");
+        println!("|");
+
+        let target: Option<String> = read_input("Enter the traffic to match");
+
+        let target = match target {
+            Some(target) => target,
+            None => {
+                println!("Invalid traffic to match");
+                return;
+            }
+        };
+
+        let protocols = DissectorRegistry::get().application_protocols();
+
+        println!("|");
+        println!("| Available protocols:");
+        for (idx, protocol) in protocols.iter().enumerate() {
+            println!("| [{}] {}", idx, protocol);
+        }
+        println!("|");
+
+        let protocol = match select_index("Enter the index of the protocol to decode as", protocols.len()) {
+            Some(protocol_index) => protocols[protocol_index],
+            None => {
+                println!("Invalid protocol index");
+                return;
+            }
+        };
+
+        match DecodeAsRule::parse(format!("{} {}", target, protocol).as_str()) {
+            Some(rule) => {
+                runner.decode_as_controller.add_rule(rule);
+                self.apply_rules(runner);
+            },
+            None => println!("Invalid traffic to match")
+        }
+    }
+
+    fn remove_rule(&self, runner: &mut Runner) {
+        println!("------ Remove decode as rule ------");
+        println!("|");
+        println!("| Select the rule you want to remove");
+        println!("|");
+        self.print_current_rules(runner);
+
+        let rule_index = select_index(
+            "Enter the index of the rule to remove",
+            runner.decode_as_controller.get_rules().len()
+        );
+
+        match rule_index {
+            Some(rule_index) => {
+                runner.decode_as_controller.remove_rule(rule_index);
+                self.apply_rules(runner);
+            },
+            None => println!("Invalid rule index")
+        }
+    }
+
+    fn apply_rules(&self, runner: &mut Runner) {
+        let rules = runner.decode_as_controller.get_rules().clone();
+        runner.sniffer.set_decode_as_rules(rules);
+    }
+
+    fn print_decode_as_options(&self) {
+        println!("------ Decode as options ------");
+        println!("|");
+        println!("| Type 'l' to list all rules");
+        println!("| Type 'a' to add a new rule");
+        println!("| Type 'r' to remove an existing rule");
+        println!("| Type 'e' to clear all rules");
+        println!("| Type 'q' to quit");
+        println!("| Type 'h' to view this instructions");
+        println!("|");
+        println!("| Each command must be followed by pressing 'Enter'");
+        println!("|");
+    }
+
+    fn interpret_command(&self, command: String, runner: &mut Runner) -> bool {
+        match command.trim() {
+            "l" => {
+                self.print_current_rules(runner);
+                true
+            },
+            "a" => {
+                self.add_rule(runner);
+                true
+            },
+            "r" => {
+                self.remove_rule(runner);
+                true
+            },
+            "e" => {
+                runner.decode_as_controller.clear_rules();
+                self.apply_rules(runner);
+                true
+            },
+            "q" => false,
+            "h" => {
+                self.print_decode_as_options();
+                true
+            },
+            _ => {
+                println!("Invalid command");
+                true
+            }
+        }
+    }
+}
+
+impl RunnerOperation for DecodeAsOperation {
+    fn run(&self, runner: &mut Runner) {
+        while self.interpret_command(read_command("Decode As"), runner) {}
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Decode as")
+    }
+
+    fn print_instructions(&self) {
+        println!("------- Decode As -------");
+        println!("|");
+        println!("| Force traffic on a TCP or UDP port, port range or address");
+        println!("| to be dissected as a specific application protocol");
+        println!("| Rules are saved between runs and applied to already collected packets");
+        println!("|");
+        self.print_decode_as_options();
+    }
+
+    fn verify_prerequisites(&self, _runner: &Runner) -> bool {
+        true
+    }
+}
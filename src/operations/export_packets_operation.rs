@@ -0,0 +1,68 @@
+use std::fs;
+
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+use crate::utils::input_util::read_input;
+
+pub struct ExportPacketsOperation {}
+
+impl ExportPacketsOperation {
+    const OPERATION: ExportPacketsOperation = ExportPacketsOperation {};
+
+    pub fn new() -> ExportPacketsOperation {
+        ExportPacketsOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for ExportPacketsOperation {
+    fn run(&self, runner: &mut Runner) {
+        let path: Option<String> = read_input("Enter the path of the export file");
+
+        let path = match path {
+            Some(path) if !path.is_empty() => path,
+            _ => {
+                println!("Invalid export file path");
+                return;
+            }
+        };
+
+        let filters = runner.filter_controller.get_active_filters();
+        let packets: Vec<String> = runner.sniffer
+            .get_sniffed_packets()
+            .iter()
+            .filter(|packet| packet.apply_filters(filters))
+            .map(|packet| packet.get_export())
+            .collect();
+
+        match fs::write(&path, packets.join("\n")) {
+            Ok(_) => println!("| Exported {} packets to {}\n", packets.len(), path),
+            Err(error) => println!("Error while exporting packets: {}", error)
+        }
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Export packet details")
+    }
+
+    fn print_instructions(&self) {
+        println!("\n------- Export packet details -------");
+        println!("|");
+        println!("| Writes the field tree of every packet matching the active filters");
+        println!("| with field names, values and byte offsets within the frame");
+        println!("|");
+    }
+
+    fn verify_prerequisites(&self, runner: &Runner) -> bool {
+        let are_packets_available = !runner.sniffer.get_sniffed_packets().is_empty();
+
+        if !are_packets_available {
+            println!("\n------------ ERROR -----------");
+            println!("|");
+            println!("| No packets available to export!");
+            println!("| Please start sniffing first");
+            println!("|\n");
+        }
+
+        are_packets_available
+    }
+}
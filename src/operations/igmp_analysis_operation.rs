@@ -0,0 +1,43 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+
+pub struct IgmpAnalysisOperation {}
+
+impl IgmpAnalysisOperation {
+    const OPERATION: IgmpAnalysisOperation = IgmpAnalysisOperation {};
+
+    pub fn new() -> IgmpAnalysisOperation {
+        IgmpAnalysisOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for IgmpAnalysisOperation {
+    fn run(&self, runner: &mut Runner) {
+        let igmp_analysis = runner.sniffer.get_igmp_analysis();
+        println!("{}", igmp_analysis.get_info());
+        println!("|");
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Multicast group analysis")
+    }
+
+    fn print_instructions(&self) {
+        println!("------------ Multicast Group Analysis -----------");
+        println!("|");
+    }
+
+    fn verify_prerequisites(&self, runner: &Runner) -> bool {
+        let has_sniffing_data = !runner.sniffer.get_sniffed_packets().is_empty();
+
+        if !has_sniffing_data {
+            println!("\n------------ ERROR -----------");
+            println!("|");
+            println!("| No sniffing data available, cannot perform multicast group analysis!");
+            println!("| Please start sniffing first");
+            println!("|\n");
+        }
+
+        has_sniffing_data
+    }
+}
\ No newline at end of file
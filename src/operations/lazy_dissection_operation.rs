@@ -0,0 +1,44 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+
+pub struct LazyDissectionOperation {}
+
+impl LazyDissectionOperation {
+    const OPERATION: LazyDissectionOperation = LazyDissectionOperation {};
+
+    pub fn new() -> LazyDissectionOperation {
+        LazyDissectionOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for LazyDissectionOperation {
+    fn run(&self, runner: &mut Runner) {
+        let lazy_dissection = !runner.sniffer.is_lazy_dissection();
+        runner.sniffer.set_lazy_dissection(lazy_dissection);
+
+        println!("------- Lazy dissection -------");
+        println!("|");
+        if lazy_dissection {
+            println!("| Lazy dissection enabled, captured frames are dissected when viewed, filtered or analyzed");
+        } else {
+            println!("| Lazy dissection disabled, captured frames are dissected as they arrive");
+        }
+        println!("|\n");
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Toggle lazy dissection")
+    }
+
+    fn print_instructions(&self) {
+        println!("\n------- Lazy dissection -------");
+        println!("|");
+        println!("| Store only raw frames while capturing and dissect them on demand,");
+        println!("| IP fragments are still reassembled as they arrive");
+        println!("|\n");
+    }
+
+    fn verify_prerequisites(&self, _runner: &Runner) -> bool {
+        true
+    }
+}
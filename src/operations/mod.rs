@@ -4,4 +4,13 @@ pub(crate) mod exit_program_operation;
 pub(crate) mod view_packets_operation;
 pub(crate) mod clear_collected_packets_operation;
 pub(crate) mod configure_filters_operation;
-pub(crate) mod traffic_analysis_operation;
\ No newline at end of file
+pub(crate) mod traffic_analysis_operation;
+pub(crate) mod arp_analysis_operation;
+pub(crate) mod neighbors_operation;
+pub(crate) mod ntp_analysis_operation;
+pub(crate) mod igmp_analysis_operation;
+pub(crate) mod decode_as_operation;
+pub(crate) mod checksum_offload_operation;
+pub(crate) mod export_packets_operation;
+pub(crate) mod lazy_dissection_operation;
+pub(crate) mod open_capture_file_operation;
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+
+pub struct NeighborsOperation {}
+
+impl NeighborsOperation {
+    const OPERATION: NeighborsOperation = NeighborsOperation {};
+
+    pub fn new() -> NeighborsOperation {
+        NeighborsOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for NeighborsOperation {
+    fn run(&self, runner: &mut Runner) {
+        let neighbors = runner.sniffer.get_neighbors();
+
+        if neighbors.is_empty() {
+            println!("| No LLDP or CDP neighbors discovered");
+        }
+
+        for neighbor in neighbors {
+            println!("{}", neighbor.get_info());
+        }
+        println!("|");
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Neighbors")
+    }
+
+    fn print_instructions(&self) {
+        println!("------------ Neighbors -----------");
+        println!("|");
+    }
+
+    fn verify_prerequisites(&self, runner: &Runner) -> bool {
+        let has_sniffing_data = !runner.sniffer.get_sniffed_packets().is_empty();
+
+        if !has_sniffing_data {
+            println!("\n------------ ERROR -----------");
+            println!("|");
+            println!("| No sniffing data available, cannot list neighbors!");
+            println!("| Please start sniffing first");
+            println!("|\n");
+        }
+
+        has_sniffing_data
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+
+pub struct NtpAnalysisOperation {}
+
+impl NtpAnalysisOperation {
+    const OPERATION: NtpAnalysisOperation = NtpAnalysisOperation {};
+
+    pub fn new() -> NtpAnalysisOperation {
+        NtpAnalysisOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for NtpAnalysisOperation {
+    fn run(&self, runner: &mut Runner) {
+        let ntp_analysis = runner.sniffer.get_ntp_analysis();
+        println!("{}", ntp_analysis.get_info());
+        println!("|");
+    }
+
+    fn get_description(&self) -> String {
+        String::from("NTP analysis")
+    }
+
+    fn print_instructions(&self) {
+        println!("------------ NTP Analysis -----------");
+        println!("|");
+    }
+
+    fn verify_prerequisites(&self, runner: &Runner) -> bool {
+        let has_sniffing_data = !runner.sniffer.get_sniffed_packets().is_empty();
+
+        if !has_sniffing_data {
+            println!("\n------------ ERROR -----------");
+            println!("|");
+            println!("| No sniffing data available, cannot perform NTP analysis!");
+            println!("| Please start sniffing first");
+            println!("|\n");
+        }
+
+        has_sniffing_data
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use crate::services::runner::Runner;
+use crate::traits::runner_operation::RunnerOperation;
+use crate::utils::input_util::read_input;
+
+pub struct OpenCaptureFileOperation {}
+
+impl OpenCaptureFileOperation {
+    const OPERATION: OpenCaptureFileOperation = OpenCaptureFileOperation {};
+
+    pub fn new() -> OpenCaptureFileOperation {
+        OpenCaptureFileOperation::OPERATION
+    }
+}
+
+impl RunnerOperation for OpenCaptureFileOperation {
+    fn run(&self, runner: &mut Runner) {
+        let path: Option<String> = read_input("Enter the path of the capture file");
+
+        match path {
+            Some(path) if !path.is_empty() => runner.sniffer.load_file(&path),
+            _ => println!("Invalid capture file path")
+        }
+    }
+
+    fn get_description(&self) -> String {
+        String::from("Open capture file")
+    }
+
+    fn print_instructions(&self) {
+        println!("\n------- Open capture file -------");
+        println!("|");
+        println!("| Appends the frames of a pcap file to the collected packets");
+        println!("| Enable lazy dissection first to load large files quickly");
+        println!("|");
+    }
+
+    fn verify_prerequisites(&self, _runner: &Runner) -> bool {
+        true
+    }
+}
@@ -1,11 +1,15 @@
+use std::any::Any;
 use std::net::Ipv4Addr;
 
-use pnet::packet::arp::{ArpHardwareType, ArpOperation, ArpPacket};
+use pnet::packet::arp::{ArpHardwareType, ArpOperation, ArpOperations, ArpPacket};
 use pnet::packet::ethernet::EtherType;
 use pnet::packet::Packet;
 use pnet::util::MacAddr;
+use crate::enums::address::Address;
 use crate::enums::protocol::Protocol;
 
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
 use crate::traits::packet_analysis::PacketAnalysis;
 
 pub struct ARP {
@@ -18,15 +22,17 @@ pub struct ARP {
     sender_proto_addr: Ipv4Addr,
     target_hw_addr: MacAddr,
     target_proto_addr: Ipv4Addr,
-    payload: Vec<u8>
+    payload: FrameBuffer
 
 }
 
 impl ARP {
     const PROTOCOL: Protocol = Protocol::ARP;
-    pub fn new(packet_data: &[u8]) -> Option<Box<dyn PacketAnalysis>> {
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
         match ArpPacket::new(packet_data) {
             Some(arp) => {
+                let header_length = ArpPacket::minimum_packet_size();
+
                 Some(Box::new(ARP {
                     hardware_type: arp.get_hardware_type(),
                     protocol_type: arp.get_protocol_type(),
@@ -37,37 +43,83 @@ impl ARP {
                     sender_proto_addr: arp.get_sender_proto_addr(),
                     target_hw_addr: arp.get_target_hw_addr(),
                     target_proto_addr: arp.get_target_proto_addr(),
-                    payload: arp.payload().to_vec()
+                    payload: packet_data.slice(header_length..header_length + arp.payload().len())
                 }))
             },
             None => None
         }
     }
+
+    pub fn sender_hw_addr(&self) -> MacAddr {
+        self.sender_hw_addr
+    }
+
+    pub fn sender_proto_addr(&self) -> Ipv4Addr {
+        self.sender_proto_addr
+    }
+
+    pub fn target_proto_addr(&self) -> Ipv4Addr {
+        self.target_proto_addr
+    }
+
+    pub fn is_request(&self) -> bool {
+        self.operation == ArpOperations::Request
+    }
+
+    pub fn is_reply(&self) -> bool {
+        self.operation == ArpOperations::Reply
+    }
+
+    pub fn is_gratuitous(&self) -> bool {
+        !self.sender_proto_addr.is_unspecified() && self.sender_proto_addr == self.target_proto_addr
+    }
+
+    pub fn operation_name(&self) -> &'static str {
+        match self.operation {
+            ArpOperations::Request if self.sender_proto_addr.is_unspecified() => "Probe",
+            ArpOperations::Request if self.is_gratuitous() => "Announcement",
+            ArpOperations::Request => "Request",
+            ArpOperations::Reply if self.is_gratuitous() => "Gratuitous",
+            ArpOperations::Reply => "Reply",
+            ArpOperation(3) => "Reverse request",
+            ArpOperation(4) => "Reverse reply",
+            _ => "Unknown"
+        }
+    }
 }
 
 impl PacketAnalysis for ARP {
     fn short_description(&self) -> String {
-        format!("ARP {}[{}] -> {}[{}] len {}", self.sender_hw_addr, self.sender_proto_addr, self.target_hw_addr, self.target_proto_addr, self.payload.len())
+        match self.operation_name() {
+            "Request" => format!("ARP request: who has {}? Tell {}", self.target_proto_addr, self.sender_proto_addr),
+            "Reply" => format!("ARP reply: {} is at {}", self.sender_proto_addr, self.sender_hw_addr),
+            "Probe" => format!("ARP probe: who has {}? Tell {}", self.target_proto_addr, self.sender_hw_addr),
+            "Announcement" => format!("ARP announcement: {} is at {}", self.sender_proto_addr, self.sender_hw_addr),
+            "Gratuitous" => format!("Gratuitous ARP: {} is at {}", self.sender_proto_addr, self.sender_hw_addr),
+            _ => format!("ARP {}[{}] -> {}[{}] len {}", self.sender_hw_addr, self.sender_proto_addr, self.target_hw_addr, self.target_proto_addr, self.payload.len())
+        }
     }
 
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
         None
     }
 
-    fn print_details(&self) {
-        println!("-------- ARP packet --------");
-        println!("|");
-        println!("| Hardware type: {:?}", self.hardware_type);
-        println!("| Protocol type: {:?}", self.protocol_type);
-        println!("| Hardware address length: {}", self.hw_addr_len);
-        println!("| Protocol address length: {}", self.proto_addr_len);
-        println!("| Operation: {:?}", self.operation);
-        println!("| Sender hardware address: {}", self.sender_hw_addr);
-        println!("| Sender protocol address: {}", self.sender_proto_addr);
-        println!("| Target hardware address: {}", self.target_hw_addr);
-        println!("| Target protocol address: {}", self.target_proto_addr);
-        println!("| Payload length: {}", self.payload.len());
-        println!("|");
+    fn fields(&self) -> Vec<PacketField> {
+        let hw_len = self.hw_addr_len as usize;
+        let proto_len = self.proto_addr_len as usize;
+
+        vec![
+            PacketField::new("arp.hw.type", "Hardware type", self.hardware_type.0, 0, 2),
+            PacketField::hex("arp.proto.type", "Protocol type", self.protocol_type.0, 2, 2),
+            PacketField::new("arp.hw.size", "Hardware address length", self.hw_addr_len, 4, 1),
+            PacketField::new("arp.proto.size", "Protocol address length", self.proto_addr_len, 5, 1),
+            PacketField::new("arp.opcode", "Operation", self.operation.0, 6, 2).with_meaning(self.operation_name()),
+            PacketField::new("arp.src.hw_mac", "Sender hardware address", self.sender_hw_addr, 8, hw_len),
+            PacketField::new("arp.src.proto_ipv4", "Sender protocol address", self.sender_proto_addr, 8 + hw_len, proto_len),
+            PacketField::new("arp.dst.hw_mac", "Target hardware address", self.target_hw_addr, 8 + hw_len + proto_len, hw_len),
+            PacketField::new("arp.dst.proto_ipv4", "Target protocol address", self.target_proto_addr, 8 + 2 * hw_len + proto_len, proto_len),
+            PacketField::generated("arp.payload_length", "Payload length", self.payload.len()),
+        ]
     }
 
     fn protocol(&self) -> Protocol {
@@ -82,11 +134,27 @@ impl PacketAnalysis for ARP {
         format!("{}", self.target_proto_addr)
     }
 
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::IPv4(self.sender_proto_addr))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::IPv4(self.target_proto_addr))
+    }
+
     fn payload_length(&self) -> usize {
         self.payload.len()
     }
 
-    fn payload(&self) -> &Vec<u8> {
-        self.payload.as_ref()
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn properties(&self) -> Vec<String> {
+        vec![self.operation_name().to_uppercase()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
\ No newline at end of file
@@ -0,0 +1,237 @@
+use std::any::Any;
+use std::net::Ipv4Addr;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::neighbor::Neighbor;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct CDP {
+    version: u8,
+    ttl: u8,
+    checksum: u16,
+    device_id: String,
+    port_id: String,
+    addresses: Vec<String>,
+    management_addresses: Vec<String>,
+    capabilities: Vec<String>,
+    software_version: Option<String>,
+    platform: Option<String>,
+    native_vlan: Option<u16>,
+    tlvs: Vec<(u16, usize, usize)>,
+    payload: FrameBuffer
+}
+
+impl CDP {
+
+    const PROTOCOL: Protocol = Protocol::CDP;
+    const HEADER_LENGTH: usize = 4;
+
+    const TLV_DEVICE_ID: u16 = 0x0001;
+    const TLV_ADDRESSES: u16 = 0x0002;
+    const TLV_PORT_ID: u16 = 0x0003;
+    const TLV_CAPABILITIES: u16 = 0x0004;
+    const TLV_SOFTWARE_VERSION: u16 = 0x0005;
+    const TLV_PLATFORM: u16 = 0x0006;
+    const TLV_NATIVE_VLAN: u16 = 0x000A;
+    const TLV_MANAGEMENT_ADDRESSES: u16 = 0x0016;
+
+    const CAPABILITY_NAMES: [&'static str; 11] = [
+        "Router", "Transparent bridge", "Source route bridge", "Switch", "Host", "IGMP",
+        "Repeater", "VoIP phone", "Remotely managed", "CVTA", "Two-port MAC relay"
+    ];
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..CDP::HEADER_LENGTH)?;
+
+        let mut cdp = CDP {
+            version: header[0],
+            ttl: header[1],
+            checksum: u16::from_be_bytes([header[2], header[3]]),
+            device_id: String::new(),
+            port_id: String::new(),
+            addresses: Vec::new(),
+            management_addresses: Vec::new(),
+            capabilities: Vec::new(),
+            software_version: None,
+            platform: None,
+            native_vlan: None,
+            tlvs: Vec::new(),
+            payload: FrameBuffer::default()
+        };
+
+        let mut offset = CDP::HEADER_LENGTH;
+
+        while offset + 4 <= packet_data.len() {
+            let tlv_type = u16::from_be_bytes([packet_data[offset], packet_data[offset + 1]]);
+            let tlv_length = u16::from_be_bytes([packet_data[offset + 2], packet_data[offset + 3]]) as usize;
+
+            let value = match packet_data.get(offset + 4..offset + tlv_length.max(4)) {
+                Some(value) => value,
+                None => break
+            };
+
+            cdp.tlvs.push((tlv_type, offset, tlv_length.max(4)));
+            offset += tlv_length.max(4);
+
+            match tlv_type {
+                CDP::TLV_DEVICE_ID => cdp.device_id = CDP::format_text(value),
+                CDP::TLV_ADDRESSES => cdp.addresses = CDP::parse_addresses(value),
+                CDP::TLV_PORT_ID => cdp.port_id = CDP::format_text(value),
+                CDP::TLV_CAPABILITIES if value.len() >= 4 => {
+                    cdp.capabilities = CDP::capability_names(u32::from_be_bytes([value[0], value[1], value[2], value[3]]));
+                },
+                CDP::TLV_SOFTWARE_VERSION => cdp.software_version = Some(CDP::format_text(value)),
+                CDP::TLV_PLATFORM => cdp.platform = Some(CDP::format_text(value)),
+                CDP::TLV_NATIVE_VLAN if value.len() >= 2 => cdp.native_vlan = Some(u16::from_be_bytes([value[0], value[1]])),
+                CDP::TLV_MANAGEMENT_ADDRESSES => cdp.management_addresses = CDP::parse_addresses(value),
+                _ => {}
+            }
+        }
+
+        cdp.payload = packet_data.slice(offset..);
+
+        Some(Box::new(cdp))
+    }
+
+    pub fn neighbor(&self) -> Neighbor {
+        let mut neighbor = Neighbor::new(CDP::PROTOCOL, self.device_id.clone(), self.port_id.clone());
+
+        neighbor.system_name = Some(self.device_id.clone());
+        neighbor.system_description = self.software_version.clone();
+        neighbor.platform = self.platform.clone();
+        neighbor.management_addresses = if self.management_addresses.is_empty() {
+            self.addresses.clone()
+        } else {
+            self.management_addresses.clone()
+        };
+        neighbor.vlan = self.native_vlan;
+        neighbor.capabilities = self.capabilities.clone();
+
+        neighbor
+    }
+
+    fn format_text(value: &[u8]) -> String {
+        String::from_utf8_lossy(value).trim_end_matches('\0').to_string()
+    }
+
+    fn parse_addresses(value: &[u8]) -> Vec<String> {
+        let mut addresses = Vec::new();
+
+        let count = match value.get(..4) {
+            Some(count) => u32::from_be_bytes([count[0], count[1], count[2], count[3]]),
+            None => return addresses
+        };
+
+        let mut offset = 4;
+
+        for _ in 0..count {
+            let protocol_length = match value.get(offset + 1) {
+                Some(protocol_length) => *protocol_length as usize,
+                None => break
+            };
+            let protocol = value.get(offset + 2..offset + 2 + protocol_length).unwrap_or(&[]);
+            offset += 2 + protocol_length;
+
+            let address_length = match value.get(offset..offset + 2) {
+                Some(length) => u16::from_be_bytes([length[0], length[1]]) as usize,
+                None => break
+            };
+            let address = match value.get(offset + 2..offset + 2 + address_length) {
+                Some(address) => address,
+                None => break
+            };
+            offset += 2 + address_length;
+
+            if protocol == [0xCC] && address.len() == 4 {
+                addresses.push(Ipv4Addr::new(address[0], address[1], address[2], address[3]).to_string());
+            }
+        }
+
+        addresses
+    }
+
+    fn address_fields(addresses: &[String]) -> Vec<PacketField> {
+        addresses
+            .iter()
+            .map(|address| PacketField::generated("cdp.address", "Address", address.as_str()))
+            .collect()
+    }
+
+    fn capability_names(capabilities: u32) -> Vec<String> {
+        CDP::CAPABILITY_NAMES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| capabilities & (1 << bit) != 0)
+            .map(|(_, name)| name.to_string())
+            .collect()
+    }
+}
+
+impl PacketAnalysis for CDP {
+    fn short_description(&self) -> String {
+        format!("CDP: device {} port {} ttl {}", self.device_id, self.port_id, self.ttl)
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("cdp.version", "Version", self.version, 0, 1),
+            PacketField::new("cdp.ttl", "Time to live", self.ttl, 1, 1),
+            PacketField::hex("cdp.checksum", "Checksum", self.checksum, 2, 2),
+        ];
+
+        for (tlv_type, offset, length) in &self.tlvs {
+            let (offset, length) = (*offset, *length);
+
+            fields.push(match *tlv_type {
+                CDP::TLV_DEVICE_ID => PacketField::new("cdp.deviceid", "Device ID", self.device_id.as_str(), offset, length),
+                CDP::TLV_PORT_ID => PacketField::new("cdp.portid", "Port ID", self.port_id.as_str(), offset, length),
+                CDP::TLV_ADDRESSES => PacketField::group("cdp.addresses", "Addresses", offset, length, CDP::address_fields(&self.addresses)),
+                CDP::TLV_MANAGEMENT_ADDRESSES => {
+                    PacketField::group("cdp.mgmt_addresses", "Management addresses", offset, length, CDP::address_fields(&self.management_addresses))
+                },
+                CDP::TLV_PLATFORM => PacketField::new("cdp.platform", "Platform", self.platform.clone().unwrap_or_default(), offset, length),
+                CDP::TLV_SOFTWARE_VERSION => {
+                    PacketField::new("cdp.software_version", "Software version", self.software_version.clone().unwrap_or_default(), offset, length)
+                },
+                CDP::TLV_NATIVE_VLAN if self.native_vlan.is_some() => {
+                    PacketField::new("cdp.native_vlan", "Native VLAN", self.native_vlan.unwrap_or(0), offset, length)
+                },
+                CDP::TLV_CAPABILITIES => PacketField::new("cdp.capabilities", "Capabilities", self.capabilities.join(", "), offset, length),
+                tlv_type => PacketField::hex("cdp.tlv", "TLV", tlv_type, offset, 2).with_meaning(format!("{} bytes", length))
+            });
+        }
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        CDP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        self.device_id.clone()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,133 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct Data {
+    entropy: f64,
+    payload: FrameBuffer
+}
+
+impl Data {
+
+    const PROTOCOL: Protocol = Protocol::Data;
+    const SHORT_PREVIEW_LENGTH: usize = 32;
+    const PREVIEW_LENGTH: usize = 256;
+    const HIGH_ENTROPY: f64 = 7.0;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        if packet_data.is_empty() {
+            return None;
+        }
+
+        Some(Box::new(Data {
+            entropy: Data::shannon_entropy(packet_data),
+            payload: packet_data.clone()
+        }))
+    }
+
+    fn shannon_entropy(data: &[u8]) -> f64 {
+        let mut counts = [0usize; 256];
+
+        for byte in data {
+            counts[*byte as usize] += 1;
+        }
+
+        counts
+            .iter()
+            .filter(|count| **count > 0)
+            .map(|count| {
+                let probability = *count as f64 / data.len() as f64;
+                probability * (1.0 / probability).log2()
+            })
+            .sum()
+    }
+
+    fn is_printable(byte: u8) -> bool {
+        byte.is_ascii_graphic() || byte == b' '
+    }
+
+    fn is_text(&self) -> bool {
+        self.payload.iter().all(|byte| Data::is_printable(*byte) || byte.is_ascii_whitespace())
+    }
+
+    fn preview(&self, length: usize) -> String {
+        let preview: String = self.payload
+            .iter()
+            .take(length)
+            .map(|byte| if Data::is_printable(*byte) { *byte as char } else { '.' })
+            .collect();
+
+        if self.payload.len() > length {
+            format!("{}...", preview)
+        } else {
+            preview
+        }
+    }
+}
+
+impl PacketAnalysis for Data {
+    fn short_description(&self) -> String {
+        format!(
+            "DATA: {} bytes \"{}\" entropy {:.2}",
+            self.payload.len(),
+            self.preview(Data::SHORT_PREVIEW_LENGTH),
+            self.entropy
+        )
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        vec![
+            PacketField::new("data.data", "Data", &self.payload[..], 0, self.payload.len()).with_children(vec![
+                PacketField::generated("data.len", "Length", self.payload.len()),
+                PacketField::generated("data.entropy", "Entropy", self.entropy).with_meaning("bits per byte"),
+                PacketField::generated("data.text", "Preview", self.preview(Data::PREVIEW_LENGTH)),
+            ]),
+        ]
+    }
+
+    fn protocol(&self) -> Protocol {
+        Data::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> Vec<String> {
+        let mut properties = Vec::new();
+
+        if self.is_text() {
+            properties.push(String::from("TEXT"));
+        }
+
+        if self.entropy > Data::HIGH_ENTROPY {
+            properties.push(String::from("HIGH_ENTROPY"));
+        }
+
+        properties
+    }
+}
@@ -0,0 +1,342 @@
+use std::any::Any;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+struct DnsQuestion {
+    name: String,
+    record_type: u16,
+    class: u16,
+    offset: usize,
+    length: usize,
+}
+
+struct DnsRecord {
+    name: String,
+    record_type: u16,
+    class: u16,
+    ttl: u32,
+    data_length: u16,
+    data: String,
+    offset: usize,
+    length: usize,
+}
+
+pub struct DNS {
+    id: u16,
+    flags: u16,
+    questions: Vec<DnsQuestion>,
+    answer_count: u16,
+    authority_count: u16,
+    additional_count: u16,
+    records: Vec<DnsRecord>,
+    payload: FrameBuffer
+}
+
+impl DNS {
+
+    const PROTOCOL: Protocol = Protocol::DNS;
+    const HEADER_LENGTH: usize = 12;
+    const MAX_POINTER_JUMPS: usize = 16;
+
+    const FLAG_RESPONSE: u16 = 0x8000;
+
+    const TYPE_A: u16 = 1;
+    const TYPE_NS: u16 = 2;
+    const TYPE_CNAME: u16 = 5;
+    const TYPE_PTR: u16 = 12;
+    const TYPE_MX: u16 = 15;
+    const TYPE_AAAA: u16 = 28;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..DNS::HEADER_LENGTH)?;
+        let flags = read_u16(header, 2);
+
+        if (flags >> 11) & 0x0F > 6 {
+            return None;
+        }
+
+        let mut dns = DNS {
+            id: read_u16(header, 0),
+            flags,
+            questions: Vec::new(),
+            answer_count: read_u16(header, 6),
+            authority_count: read_u16(header, 8),
+            additional_count: read_u16(header, 10),
+            records: Vec::new(),
+            payload: FrameBuffer::default()
+        };
+
+        let mut offset = DNS::HEADER_LENGTH;
+
+        for _ in 0..read_u16(header, 4) {
+            let (name, name_end) = DNS::read_name(packet_data, offset)?;
+            let fixed = packet_data.get(name_end..name_end + 4)?;
+
+            dns.questions.push(DnsQuestion {
+                name,
+                record_type: read_u16(fixed, 0),
+                class: read_u16(fixed, 2),
+                offset,
+                length: name_end + 4 - offset,
+            });
+            offset = name_end + 4;
+        }
+
+        let record_count = dns.answer_count as usize + dns.authority_count as usize + dns.additional_count as usize;
+
+        for _ in 0..record_count {
+            let record = DNS::read_record(packet_data, offset)?;
+            offset += record.length;
+            dns.records.push(record);
+        }
+
+        dns.payload = packet_data.slice(offset..);
+
+        Some(Box::new(dns))
+    }
+
+    fn read_record(message: &[u8], offset: usize) -> Option<DnsRecord> {
+        let (name, name_end) = DNS::read_name(message, offset)?;
+        let fixed = message.get(name_end..name_end + 10)?;
+        let record_type = read_u16(fixed, 0);
+        let data_length = read_u16(fixed, 8);
+        let data_offset = name_end + 10;
+        let data = message.get(data_offset..data_offset + data_length as usize)?;
+
+        Some(DnsRecord {
+            name,
+            record_type,
+            class: read_u16(fixed, 2),
+            ttl: u32::from_be_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]),
+            data_length,
+            data: DNS::format_data(message, record_type, data_offset, data),
+            offset,
+            length: data_offset + data_length as usize - offset,
+        })
+    }
+
+    /// Reads a possibly compressed name, returning it with the offset right after its encoding
+    fn read_name(message: &[u8], mut offset: usize) -> Option<(String, usize)> {
+        let mut labels = Vec::new();
+        let mut end = None;
+        let mut jumps = 0;
+
+        loop {
+            let length = *message.get(offset)? as usize;
+
+            match length {
+                0 => {
+                    offset += 1;
+                    break;
+                },
+                _ if length & 0xC0 == 0xC0 => {
+                    jumps += 1;
+                    if jumps > DNS::MAX_POINTER_JUMPS {
+                        return None;
+                    }
+
+                    end.get_or_insert(offset + 2);
+                    offset = ((length & 0x3F) << 8) | *message.get(offset + 1)? as usize;
+                },
+                _ if length & 0xC0 == 0 => {
+                    labels.push(String::from_utf8_lossy(message.get(offset + 1..offset + 1 + length)?).to_string());
+                    offset += 1 + length;
+                },
+                _ => return None
+            }
+        }
+
+        let name = if labels.is_empty() { String::from("<Root>") } else { labels.join(".") };
+
+        Some((name, end.unwrap_or(offset)))
+    }
+
+    fn format_data(message: &[u8], record_type: u16, data_offset: usize, data: &[u8]) -> String {
+        match (record_type, data.len()) {
+            (DNS::TYPE_A, 4) => Ipv4Addr::new(data[0], data[1], data[2], data[3]).to_string(),
+            (DNS::TYPE_AAAA, 16) => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(data);
+                Ipv6Addr::from(octets).to_string()
+            },
+            (DNS::TYPE_NS | DNS::TYPE_CNAME | DNS::TYPE_PTR, _) => match DNS::read_name(message, data_offset) {
+                Some((name, _)) => name,
+                None => String::from("<invalid name>")
+            },
+            (DNS::TYPE_MX, 3..) => match DNS::read_name(message, data_offset + 2) {
+                Some((name, _)) => format!("{} {}", read_u16(data, 0), name),
+                None => String::from("<invalid name>")
+            },
+            _ => format!("<{} bytes>", data.len())
+        }
+    }
+
+    fn type_name(record_type: u16) -> String {
+        match record_type {
+            DNS::TYPE_A => String::from("A"),
+            DNS::TYPE_NS => String::from("NS"),
+            DNS::TYPE_CNAME => String::from("CNAME"),
+            6 => String::from("SOA"),
+            DNS::TYPE_PTR => String::from("PTR"),
+            DNS::TYPE_MX => String::from("MX"),
+            16 => String::from("TXT"),
+            DNS::TYPE_AAAA => String::from("AAAA"),
+            33 => String::from("SRV"),
+            41 => String::from("OPT"),
+            65 => String::from("HTTPS"),
+            255 => String::from("ANY"),
+            _ => format!("TYPE{}", record_type)
+        }
+    }
+
+    fn class_name(class: u16) -> &'static str {
+        // mDNS uses the top bit for the cache flush / unicast response flag
+        match class & 0x7FFF {
+            1 => "IN",
+            3 => "CH",
+            4 => "HS",
+            255 => "ANY",
+            _ => "Unknown"
+        }
+    }
+
+    fn is_response(&self) -> bool {
+        self.flags & DNS::FLAG_RESPONSE != 0
+    }
+
+    fn opcode(&self) -> u16 {
+        (self.flags >> 11) & 0x0F
+    }
+
+    fn rcode(&self) -> u16 {
+        self.flags & 0x0F
+    }
+
+    fn rcode_name(&self) -> &'static str {
+        match self.rcode() {
+            0 => "No error",
+            1 => "Format error",
+            2 => "Server failure",
+            3 => "No such name",
+            4 => "Not implemented",
+            5 => "Refused",
+            _ => "Unknown"
+        }
+    }
+
+    fn section_name(&self, record_idx: usize) -> (&'static str, &'static str) {
+        if record_idx < self.answer_count as usize {
+            ("dns.answer", "Answer")
+        } else if record_idx < self.answer_count as usize + self.authority_count as usize {
+            ("dns.authority", "Authoritative nameserver")
+        } else {
+            ("dns.additional", "Additional record")
+        }
+    }
+}
+
+impl PacketAnalysis for DNS {
+    fn short_description(&self) -> String {
+        let mut description = match self.is_response() {
+            true => format!("DNS: response 0x{:04x}", self.id),
+            false => format!("DNS: query 0x{:04x}", self.id)
+        };
+
+        for question in &self.questions {
+            description.push_str(format!(" {} {}", DNS::type_name(question.record_type), question.name).as_str());
+        }
+
+        if self.is_response() && self.rcode() != 0 {
+            description.push_str(format!(" ({})", self.rcode_name()).as_str());
+        }
+
+        for record in self.records.iter().take(self.answer_count as usize) {
+            description.push_str(format!(" {} {}", DNS::type_name(record.record_type), record.data).as_str());
+        }
+
+        description
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::hex("dns.id", "Transaction ID", self.id, 0, 2),
+            PacketField::hex("dns.flags", "Flags", self.flags, 2, 2).with_children(vec![
+                PacketField::new("dns.flags.response", "Response", self.is_response(), 2, 1),
+                PacketField::new("dns.flags.opcode", "Opcode", self.opcode(), 2, 1),
+                PacketField::new("dns.flags.rcode", "Reply code", self.rcode(), 3, 1).with_meaning(self.rcode_name()),
+            ]),
+            PacketField::new("dns.count.queries", "Questions", self.questions.len(), 4, 2),
+            PacketField::new("dns.count.answers", "Answer RRs", self.answer_count, 6, 2),
+            PacketField::new("dns.count.auth_rr", "Authority RRs", self.authority_count, 8, 2),
+            PacketField::new("dns.count.add_rr", "Additional RRs", self.additional_count, 10, 2),
+        ];
+
+        for question in &self.questions {
+            let name_length = question.length - 4;
+
+            fields.push(PacketField::group("dns.qry", "Query", question.offset, question.length, vec![
+                PacketField::new("dns.qry.name", "Name", question.name.as_str(), question.offset, name_length),
+                PacketField::new("dns.qry.type", "Type", question.record_type, question.offset + name_length, 2)
+                    .with_meaning(DNS::type_name(question.record_type)),
+                PacketField::new("dns.qry.class", "Class", question.class, question.offset + name_length + 2, 2)
+                    .with_meaning(DNS::class_name(question.class)),
+            ]));
+        }
+
+        for (idx, record) in self.records.iter().enumerate() {
+            let (name, label) = self.section_name(idx);
+            let name_length = record.length - 10 - record.data_length as usize;
+            let fixed_offset = record.offset + name_length;
+
+            fields.push(PacketField::group(name, label, record.offset, record.length, vec![
+                PacketField::new("dns.resp.name", "Name", record.name.as_str(), record.offset, name_length),
+                PacketField::new("dns.resp.type", "Type", record.record_type, fixed_offset, 2)
+                    .with_meaning(DNS::type_name(record.record_type)),
+                PacketField::new("dns.resp.class", "Class", record.class, fixed_offset + 2, 2)
+                    .with_meaning(DNS::class_name(record.class)),
+                PacketField::new("dns.resp.ttl", "Time to live", record.ttl, fixed_offset + 4, 4),
+                PacketField::new("dns.resp.len", "Data length", record.data_length, fixed_offset + 8, 2),
+                PacketField::new("dns.resp.data", "Data", record.data.as_str(), fixed_offset + 10, record.data_length as usize),
+            ]));
+        }
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        DNS::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
@@ -0,0 +1,135 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::{FieldValue, PacketField};
+
+use crate::protocols::ethernet::Ethernet;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct ERSPAN {
+    version: u8,
+    vlan: u16,
+    class_of_service: u8,
+    session_id: u16,
+    index: Option<u32>,
+    timestamp: Option<u32>,
+    hardware_id: Option<u8>,
+    subheader: bool,
+    payload: FrameBuffer
+}
+
+impl ERSPAN {
+
+    const PROTOCOL: Protocol = Protocol::ERSPAN;
+    const TYPE_II_HEADER_LENGTH: usize = 8;
+    const TYPE_III_HEADER_LENGTH: usize = 12;
+    const TYPE_III_SUBHEADER_LENGTH: usize = 8;
+
+    pub fn new(version: u8, packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header_length = match version {
+            2 => ERSPAN::TYPE_II_HEADER_LENGTH,
+            3 => ERSPAN::TYPE_III_HEADER_LENGTH,
+            _ => return None
+        };
+
+        let header = packet_data.get(..header_length)?;
+        let version_and_vlan = u16::from_be_bytes([header[0], header[1]]);
+        let session = u16::from_be_bytes([header[2], header[3]]);
+
+        let mut erspan = ERSPAN {
+            version,
+            vlan: version_and_vlan & 0x0FFF,
+            class_of_service: (session >> 13) as u8,
+            session_id: session & 0x03FF,
+            index: None,
+            timestamp: None,
+            hardware_id: None,
+            subheader: false,
+            payload: FrameBuffer::default()
+        };
+
+        let mut payload_offset = header_length;
+
+        if version == 2 {
+            erspan.index = Some(u32::from_be_bytes([0, header[5] & 0x0F, header[6], header[7]]));
+        } else {
+            erspan.timestamp = Some(u32::from_be_bytes([header[4], header[5], header[6], header[7]]));
+            erspan.hardware_id = Some(((header[10] & 0x03) << 4) | (header[11] >> 4));
+
+            if header[11] & 0x01 != 0 {
+                erspan.subheader = true;
+                payload_offset += ERSPAN::TYPE_III_SUBHEADER_LENGTH;
+            }
+        }
+
+        erspan.payload = packet_data.checked_slice(payload_offset..)?;
+
+        Some(Box::new(erspan))
+    }
+}
+
+impl PacketAnalysis for ERSPAN {
+    fn short_description(&self) -> String {
+        format!("ERSPAN type {}: session {} vlan {} len {}", self.version, self.session_id, self.vlan, self.payload.len())
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        Ethernet::new(&self.payload)
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("erspan.ver", "Type", self.version, 0, 1),
+            PacketField::new("erspan.vlan", "VLAN", self.vlan, 0, 2),
+            PacketField::new("erspan.cos", "Class of service", self.class_of_service, 2, 1),
+            PacketField::new("erspan.spanid", "Session ID", self.session_id, 2, 2),
+        ];
+
+        if let Some(index) = self.index {
+            fields.push(PacketField::new("erspan.index", "Index", index, 4, 4));
+        }
+        if let Some(timestamp) = self.timestamp {
+            fields.push(PacketField::new("erspan.timestamp", "Timestamp", timestamp, 4, 4));
+        }
+        if let Some(hardware_id) = self.hardware_id {
+            fields.push(PacketField::new("erspan.hwid", "Hardware ID", hardware_id, 10, 2));
+        }
+        if self.subheader {
+            fields.push(PacketField::new(
+                "erspan.subheader",
+                "Platform specific subheader",
+                FieldValue::None,
+                ERSPAN::TYPE_III_HEADER_LENGTH,
+                ERSPAN::TYPE_III_SUBHEADER_LENGTH
+            ));
+        }
+        fields.push(PacketField::generated("erspan.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        ERSPAN::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
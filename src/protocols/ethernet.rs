@@ -1,38 +1,59 @@
+use std::any::Any;
+
 use pnet::packet::ethernet::{EthernetPacket, EtherType};
 use pnet::packet::Packet;
 use pnet::util::MacAddr;
+use crate::enums::address::Address;
 use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
 
-use crate::protocols::arp::ARP;
-use crate::protocols::ipv4::IPv4;
-use crate::protocols::ipv6::IPv6;
+use crate::protocols::llc::LLC;
+use crate::protocols::malformed::{Malformed, MalformedReason};
+use crate::services::dissector_registry::{DissectorKey, DissectorRegistry};
 use crate::traits::packet_analysis::PacketAnalysis;
 
 pub struct Ethernet {
     destination: MacAddr,
     source: MacAddr,
     ethertype: EtherType,
-    payload: Vec<u8>
+    payload: FrameBuffer
 }
 
 
 impl Ethernet {
 
     const PROTOCOL: Protocol = Protocol::Ethernet;
+    const MAX_FRAME_LENGTH: u16 = 1500;
 
-    pub fn new(packet_data: &[u8]) -> Option<Box<dyn PacketAnalysis>> {
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
         match EthernetPacket::new(packet_data) {
             Some(ethernet) => {
+                let header_length = EthernetPacket::minimum_packet_size();
+
                 Some(Box::new(Ethernet {
                     destination: ethernet.get_destination(),
                     source: ethernet.get_source(),
                     ethertype: ethernet.get_ethertype(),
-                    payload: ethernet.payload().to_vec()
+                    payload: packet_data.slice(header_length..header_length + ethernet.payload().len())
                 }))
             },
-            None => None
+            None => Malformed::new(
+                Protocol::Ethernet,
+                MalformedReason::TooShort { required: 14, available: packet_data.len() },
+                packet_data.len(),
+                packet_data
+            )
         }
     }
+
+    pub fn is_broadcast(&self) -> bool {
+        self.destination.is_broadcast()
+    }
+
+    pub fn is_multicast(&self) -> bool {
+        self.destination.is_multicast() && !self.destination.is_broadcast()
+    }
 }
 
 impl PacketAnalysis for Ethernet {
@@ -42,21 +63,26 @@ impl PacketAnalysis for Ethernet {
 
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
         match self.ethertype {
-            EtherType(0x0800) => IPv4::new(self.payload.as_slice()),
-            EtherType(0x86DD) => IPv6::new(self.payload.as_slice()),
-            EtherType(0x0806) => ARP::new(self.payload.as_slice()),
-            _ => None
+            EtherType(length) if length <= Ethernet::MAX_FRAME_LENGTH => {
+                let length = (length as usize).min(self.payload.len());
+                LLC::new(&self.payload.slice(..length))
+            },
+            EtherType(ethertype) => DissectorRegistry::get().dissect(DissectorKey::EtherType(ethertype), &self.payload)
         }
     }
 
-    fn print_details(&self) {
-        println!("--------- Ethernet packet ---------");
-        println!("|");
-        println!("| Destination: {}", self.destination);
-        println!("| Source: {}", self.source);
-        println!("| EtherType: {:?}", self.ethertype);
-        println!("| Payload length: {}", self.payload.len());
-        println!("|");
+    fn fields(&self) -> Vec<PacketField> {
+        let ethertype = match self.ethertype {
+            EtherType(length) if length <= Ethernet::MAX_FRAME_LENGTH => PacketField::new("eth.len", "Length", length, 12, 2),
+            ethertype => PacketField::hex("eth.type", "EtherType", ethertype.0, 12, 2).with_meaning(ethertype)
+        };
+
+        vec![
+            PacketField::new("eth.dst", "Destination", self.destination, 0, 6),
+            PacketField::new("eth.src", "Source", self.source, 6, 6),
+            ethertype,
+            PacketField::generated("eth.payload_length", "Payload length", self.payload.len()),
+        ]
     }
 
     fn protocol(&self) -> Protocol {
@@ -71,11 +97,23 @@ impl PacketAnalysis for Ethernet {
         format!("{}", self.destination)
     }
 
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::Mac(self.source))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::Mac(self.destination))
+    }
+
     fn payload_length(&self) -> usize {
         self.payload.len()
     }
 
-    fn payload(&self) -> &Vec<u8> {
-        self.payload.as_ref()
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
\ No newline at end of file
@@ -0,0 +1,135 @@
+use std::any::Any;
+
+use pnet::packet::ethernet::EtherType;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::services::dissector_registry::{DissectorKey, DissectorRegistry};
+use crate::traits::packet_analysis::PacketAnalysis;
+
+struct GeneveOption {
+    class: u16,
+    option_type: u8,
+    data: Vec<u8>,
+}
+
+pub struct Geneve {
+    version: u8,
+    oam: bool,
+    critical: bool,
+    protocol_type: EtherType,
+    network_identifier: u32,
+    options: Vec<GeneveOption>,
+    payload: FrameBuffer
+}
+
+impl Geneve {
+
+    const PROTOCOL: Protocol = Protocol::Geneve;
+    const HEADER_LENGTH: usize = 8;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..Geneve::HEADER_LENGTH)?;
+        let version = header[0] >> 6;
+
+        if version != 0 {
+            return None;
+        }
+
+        let options_end = Geneve::HEADER_LENGTH + (header[0] & 0x3F) as usize * 4;
+        let options_data = packet_data.get(Geneve::HEADER_LENGTH..options_end)?;
+
+        Some(Box::new(Geneve {
+            version,
+            oam: header[1] & 0x80 != 0,
+            critical: header[1] & 0x40 != 0,
+            protocol_type: EtherType(u16::from_be_bytes([header[2], header[3]])),
+            network_identifier: u32::from_be_bytes([0, header[4], header[5], header[6]]),
+            options: Geneve::parse_options(options_data),
+            payload: packet_data.slice(options_end..)
+        }))
+    }
+
+    fn parse_options(options_data: &[u8]) -> Vec<GeneveOption> {
+        let mut options = Vec::new();
+        let mut offset = 0;
+
+        while offset + 4 <= options_data.len() {
+            let data_length = (options_data[offset + 3] & 0x1F) as usize * 4;
+            let data_end = (offset + 4 + data_length).min(options_data.len());
+
+            options.push(GeneveOption {
+                class: u16::from_be_bytes([options_data[offset], options_data[offset + 1]]),
+                option_type: options_data[offset + 2],
+                data: options_data[offset + 4..data_end].to_vec(),
+            });
+
+            offset = data_end;
+        }
+
+        options
+    }
+}
+
+impl PacketAnalysis for Geneve {
+    fn short_description(&self) -> String {
+        format!("GENEVE: VNI {} {:?} len {}", self.network_identifier, self.protocol_type, self.payload.len())
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        DissectorRegistry::get().dissect(DissectorKey::EtherType(self.protocol_type.0), &self.payload)
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("geneve.version", "Version", self.version, 0, 1),
+            PacketField::new("geneve.flags.oam", "OAM", self.oam, 1, 1),
+            PacketField::new("geneve.flags.critical", "Critical options present", self.critical, 1, 1),
+            PacketField::hex("geneve.proto_type", "Protocol type", self.protocol_type.0, 2, 2).with_meaning(self.protocol_type),
+            PacketField::new("geneve.vni", "Virtual network identifier", self.network_identifier, 4, 3),
+        ];
+
+        let mut offset = Geneve::HEADER_LENGTH;
+
+        for option in &self.options {
+            let length = 4 + option.data.len();
+
+            fields.push(PacketField::group("geneve.option", "Option", offset, length, vec![
+                PacketField::hex("geneve.option.class", "Class", option.class, offset, 2),
+                PacketField::hex("geneve.option.type", "Type", option.option_type, offset + 2, 1),
+                PacketField::new("geneve.option.data", "Data", option.data.as_slice(), offset + 4, option.data.len()),
+            ]));
+
+            offset += length;
+        }
+
+        fields.push(PacketField::generated("geneve.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        Geneve::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,190 @@
+use std::any::Any;
+
+use pnet::packet::ethernet::EtherType;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::erspan::ERSPAN;
+use crate::protocols::ethernet::Ethernet;
+use crate::services::dissector_registry::{DissectorKey, DissectorRegistry};
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct GRE {
+    checksum_present: bool,
+    key_present: bool,
+    sequence_present: bool,
+    version: u8,
+    protocol_type: EtherType,
+    checksum: Option<u16>,
+    key: Option<u32>,
+    sequence: Option<u32>,
+    acknowledgement: Option<u32>,
+    payload: FrameBuffer
+}
+
+impl GRE {
+
+    const PROTOCOL: Protocol = Protocol::GRE;
+    const FLAG_CHECKSUM: u16 = 0x8000;
+    const FLAG_ROUTING: u16 = 0x4000;
+    const FLAG_KEY: u16 = 0x2000;
+    const FLAG_SEQUENCE: u16 = 0x1000;
+    const FLAG_ACKNOWLEDGEMENT: u16 = 0x0080;
+
+    const TRANSPARENT_ETHERNET_BRIDGING: u16 = 0x6558;
+    const ERSPAN_TYPE_II: u16 = 0x88BE;
+    const ERSPAN_TYPE_III: u16 = 0x22EB;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let flags_and_version = read_u16(packet_data, 0)?;
+        let version = (flags_and_version & 0x0007) as u8;
+        let mut offset = 4;
+
+        if version > 1 {
+            return None;
+        }
+
+        let checksum = if flags_and_version & (GRE::FLAG_CHECKSUM | GRE::FLAG_ROUTING) != 0 {
+            offset += 4;
+            Some(read_u16(packet_data, 4)?)
+        } else {
+            None
+        };
+
+        let key = if flags_and_version & GRE::FLAG_KEY != 0 {
+            offset += 4;
+            Some(read_u32(packet_data, offset - 4)?)
+        } else {
+            None
+        };
+
+        let sequence = if flags_and_version & GRE::FLAG_SEQUENCE != 0 {
+            offset += 4;
+            Some(read_u32(packet_data, offset - 4)?)
+        } else {
+            None
+        };
+
+        let acknowledgement = if version == 1 && flags_and_version & GRE::FLAG_ACKNOWLEDGEMENT != 0 {
+            offset += 4;
+            Some(read_u32(packet_data, offset - 4)?)
+        } else {
+            None
+        };
+
+        Some(Box::new(GRE {
+            checksum_present: flags_and_version & GRE::FLAG_CHECKSUM != 0,
+            key_present: key.is_some(),
+            sequence_present: sequence.is_some(),
+            version,
+            protocol_type: EtherType(read_u16(packet_data, 2)?),
+            checksum,
+            key,
+            sequence,
+            acknowledgement,
+            payload: packet_data.checked_slice(offset..)?
+        }))
+    }
+
+    fn is_nvgre(&self) -> bool {
+        self.protocol_type == EtherType(GRE::TRANSPARENT_ETHERNET_BRIDGING) && self.key_present
+    }
+}
+
+impl PacketAnalysis for GRE {
+    fn short_description(&self) -> String {
+        match self.key {
+            Some(key) if self.is_nvgre() => format!(
+                "NVGRE: VSID {} flow {} len {}",
+                key >> 8,
+                key & 0xFF,
+                self.payload.len()
+            ),
+            Some(key) => format!("GRE: {:?} key {} len {}", self.protocol_type, key, self.payload.len()),
+            None => format!("GRE: {:?} len {}", self.protocol_type, self.payload.len())
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        match self.protocol_type {
+            EtherType(GRE::ERSPAN_TYPE_II) if !self.sequence_present => Ethernet::new(&self.payload),
+            EtherType(GRE::ERSPAN_TYPE_II) => ERSPAN::new(2, &self.payload),
+            EtherType(GRE::ERSPAN_TYPE_III) => ERSPAN::new(3, &self.payload),
+            EtherType(protocol_type) => DissectorRegistry::get().dissect(DissectorKey::EtherType(protocol_type), &self.payload)
+        }
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("gre.flags.checksum", "Checksum present", self.checksum_present, 0, 2),
+            PacketField::new("gre.flags.key", "Key present", self.key_present, 0, 2),
+            PacketField::new("gre.flags.sequence", "Sequence present", self.sequence_present, 0, 2),
+            PacketField::new("gre.version", "Version", self.version, 1, 1),
+            PacketField::hex("gre.proto", "Protocol type", self.protocol_type.0, 2, 2).with_meaning(self.protocol_type),
+        ];
+
+        let mut offset = 4;
+
+        if let Some(checksum) = self.checksum {
+            fields.push(PacketField::hex("gre.checksum", "Checksum", checksum, offset, 2));
+            offset += 4;
+        }
+        if let Some(key) = self.key {
+            if self.version == 1 {
+                fields.push(PacketField::new("gre.key.payload_length", "Key payload length", key >> 16, offset, 2));
+                fields.push(PacketField::new("gre.key.call_id", "Call ID", key & 0xFFFF, offset + 2, 2));
+            } else if self.is_nvgre() {
+                fields.push(PacketField::new("gre.key.nvgre_vsid", "Virtual subnet ID", key >> 8, offset, 3));
+                fields.push(PacketField::new("gre.key.nvgre_flow_id", "Flow ID", key & 0xFF, offset + 3, 1));
+            } else {
+                fields.push(PacketField::new("gre.key", "Key", key, offset, 4));
+            }
+            offset += 4;
+        }
+        if let Some(sequence) = self.sequence {
+            fields.push(PacketField::new("gre.sequence_number", "Sequence", sequence, offset, 4));
+            offset += 4;
+        }
+        if let Some(acknowledgement) = self.acknowledgement {
+            fields.push(PacketField::new("gre.ack_number", "Acknowledgement", acknowledgement, offset, 4));
+        }
+        fields.push(PacketField::generated("gre.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        GRE::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
@@ -0,0 +1,210 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+const HTTP_METHODS: [&str; 9] = ["GET", "POST", "PUT", "DELETE", "HEAD", "OPTIONS", "PATCH", "CONNECT", "TRACE"];
+
+enum HttpStartLine {
+    Request {
+        method: String,
+        uri: String,
+        version: String,
+    },
+    Response {
+        version: String,
+        status_code: u16,
+        reason: String,
+    },
+}
+
+pub struct HTTP {
+    start_line: HttpStartLine,
+    headers: Vec<(String, String)>,
+    headers_start: usize,
+    header_ranges: Vec<(usize, usize)>,
+    host: Option<String>,
+    content_type: Option<String>,
+    content_length: Option<usize>,
+    chunked: bool,
+    payload: FrameBuffer
+}
+
+impl HTTP {
+
+    const PROTOCOL: Protocol = Protocol::HTTP;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let (start_line, mut line_start) = match HTTP::read_line(packet_data, 0) {
+            Some((line, next)) => (HTTP::parse_start_line(line)?, next),
+            None => return None
+        };
+
+        let headers_start = line_start;
+        let mut headers = Vec::new();
+        let mut header_ranges = Vec::new();
+        let mut body_start = packet_data.len();
+
+        while let Some((line, next)) = HTTP::read_line(packet_data, line_start) {
+            if line.is_empty() {
+                body_start = next;
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+                header_ranges.push((line_start, line.len()));
+            }
+
+            line_start = next;
+        }
+
+        let mut http = HTTP {
+            start_line,
+            headers,
+            headers_start,
+            header_ranges,
+            host: None,
+            content_type: None,
+            content_length: None,
+            chunked: false,
+            payload: packet_data.slice(body_start..)
+        };
+
+        http.read_known_headers();
+
+        Some(Box::new(http))
+    }
+
+    fn read_line(data: &[u8], start: usize) -> Option<(String, usize)> {
+        if start >= data.len() {
+            return None;
+        }
+
+        let end = data[start..].iter().position(|byte| *byte == b'\n')? + start;
+        let line = String::from_utf8_lossy(&data[start..end]).trim_end_matches('\r').to_string();
+
+        Some((line, end + 1))
+    }
+
+    fn parse_start_line(line: String) -> Option<HttpStartLine> {
+        let mut parts = line.splitn(3, ' ');
+        let first = parts.next()?;
+        let second = parts.next()?;
+        let third = parts.next().unwrap_or("");
+
+        if first.starts_with("HTTP/1.") {
+            match second.parse::<u16>() {
+                Ok(status_code) if second.len() == 3 => Some(HttpStartLine::Response {
+                    version: first.to_string(),
+                    status_code,
+                    reason: third.to_string(),
+                }),
+                _ => None
+            }
+        } else if HTTP_METHODS.contains(&first) && third.starts_with("HTTP/1.") {
+            Some(HttpStartLine::Request {
+                method: first.to_string(),
+                uri: second.to_string(),
+                version: third.to_string(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn read_known_headers(&mut self) {
+        for (name, value) in &self.headers {
+            match name.to_lowercase().as_str() {
+                "host" => self.host = Some(value.clone()),
+                "content-type" => self.content_type = Some(value.clone()),
+                "content-length" => self.content_length = value.parse().ok(),
+                "transfer-encoding" => self.chunked = value.to_lowercase().contains("chunked"),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl PacketAnalysis for HTTP {
+    fn short_description(&self) -> String {
+        match &self.start_line {
+            HttpStartLine::Request { method, uri, .. } => format!("HTTP: {} {}", method, uri),
+            HttpStartLine::Response { status_code, reason, .. } => format!("HTTP: {} {}", status_code, reason),
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = match &self.start_line {
+            HttpStartLine::Request { method, uri, version } => vec![
+                PacketField::new("http.request.method", "Method", method.as_str(), 0, method.len()),
+                PacketField::new("http.request.uri", "URI", uri.as_str(), method.len() + 1, uri.len()),
+                PacketField::new("http.request.version", "Version", version.as_str(), method.len() + uri.len() + 2, version.len()),
+            ],
+            HttpStartLine::Response { version, status_code, reason } => vec![
+                PacketField::new("http.response.version", "Version", version.as_str(), 0, version.len()),
+                PacketField::new("http.response.code", "Status code", *status_code, version.len() + 1, 3),
+                PacketField::new("http.response.phrase", "Reason", reason.as_str(), version.len() + 5, reason.len()),
+            ]
+        };
+
+        let headers: Vec<PacketField> = self.headers
+            .iter()
+            .zip(self.header_ranges.iter())
+            .map(|((name, value), (offset, length))| {
+                let field_name = match name.to_lowercase().as_str() {
+                    "host" => "http.host",
+                    "content-type" => "http.content_type",
+                    "content-length" => "http.content_length_header",
+                    "transfer-encoding" => "http.transfer_encoding",
+                    _ => "http.header"
+                };
+
+                PacketField::new(field_name, name.as_str(), value.as_str(), *offset, *length)
+            })
+            .collect();
+
+        let headers_end = self.header_ranges.last().map_or(self.headers_start, |(offset, length)| offset + length);
+
+        fields.push(PacketField::group("http.headers", "Headers", self.headers_start, headers_end - self.headers_start, headers));
+
+        if let Some(content_length) = self.content_length {
+            fields.push(PacketField::generated("http.content_length", "Content length", content_length));
+        }
+        fields.push(PacketField::generated("http.chunked", "Chunked", self.chunked));
+        fields.push(PacketField::generated("http.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        HTTP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        self.host.clone().unwrap_or_default()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,176 @@
+use std::any::Any;
+
+use crate::enums::checksum_status::ChecksumStatus;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::malformed::{Malformed, MalformedReason};
+use crate::traits::packet_analysis::PacketAnalysis;
+use crate::utils::checksum_util::{checksum_status, pseudo_header_sum, sum_words, CHECKSUM_PARTIAL};
+
+pub struct ICMP {
+    protocol: Protocol,
+    message_type: u8,
+    code: u8,
+    checksum: u16,
+    rest_of_header: u32,
+    message_sum: u32,
+    message_length: usize,
+    checksum_status: ChecksumStatus,
+    payload: FrameBuffer
+}
+
+impl ICMP {
+
+    const HEADER_LENGTH: usize = 8;
+    const IPV6_NEXT_HEADER: u8 = 58;
+
+    const ECHO_REPLY: u8 = 0;
+    const ECHO_REQUEST: u8 = 8;
+    const V6_ECHO_REQUEST: u8 = 128;
+    const V6_ECHO_REPLY: u8 = 129;
+
+    pub fn new(protocol: Protocol, packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = match packet_data.get(..ICMP::HEADER_LENGTH) {
+            Some(header) => header,
+            None => return Malformed::new(
+                protocol,
+                MalformedReason::TooShort { required: ICMP::HEADER_LENGTH, available: packet_data.len() },
+                packet_data.len(),
+                packet_data
+            )
+        };
+
+        Some(Box::new(ICMP {
+            protocol,
+            message_type: header[0],
+            code: header[1],
+            checksum: u16::from_be_bytes([header[2], header[3]]),
+            rest_of_header: u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+            message_sum: sum_words(packet_data),
+            message_length: packet_data.len(),
+            checksum_status: ChecksumStatus::Unverified(CHECKSUM_PARTIAL),
+            payload: packet_data.slice(ICMP::HEADER_LENGTH..)
+        }))
+    }
+
+    fn is_echo(&self) -> bool {
+        match self.protocol {
+            Protocol::ICMPv6 => matches!(self.message_type, ICMP::V6_ECHO_REQUEST | ICMP::V6_ECHO_REPLY),
+            _ => matches!(self.message_type, ICMP::ECHO_REQUEST | ICMP::ECHO_REPLY)
+        }
+    }
+
+    fn message_type_name(&self) -> &'static str {
+        match (self.protocol, self.message_type) {
+            (Protocol::ICMPv6, 1) => "Destination unreachable",
+            (Protocol::ICMPv6, 2) => "Packet too big",
+            (Protocol::ICMPv6, 3) => "Time exceeded",
+            (Protocol::ICMPv6, 4) => "Parameter problem",
+            (Protocol::ICMPv6, ICMP::V6_ECHO_REQUEST) => "Echo request",
+            (Protocol::ICMPv6, ICMP::V6_ECHO_REPLY) => "Echo reply",
+            (Protocol::ICMPv6, 133) => "Router solicitation",
+            (Protocol::ICMPv6, 134) => "Router advertisement",
+            (Protocol::ICMPv6, 135) => "Neighbor solicitation",
+            (Protocol::ICMPv6, 136) => "Neighbor advertisement",
+            (Protocol::ICMPv6, 137) => "Redirect",
+            (Protocol::ICMPv6, _) => "Unknown",
+            (_, ICMP::ECHO_REPLY) => "Echo reply",
+            (_, 3) => "Destination unreachable",
+            (_, 5) => "Redirect",
+            (_, ICMP::ECHO_REQUEST) => "Echo request",
+            (_, 11) => "Time exceeded",
+            (_, 12) => "Parameter problem",
+            (_, 13) => "Timestamp request",
+            (_, 14) => "Timestamp reply",
+            _ => "Unknown"
+        }
+    }
+
+    fn field_prefix(&self) -> &'static str {
+        match self.protocol {
+            Protocol::ICMPv6 => "icmpv6",
+            _ => "icmp"
+        }
+    }
+}
+
+impl PacketAnalysis for ICMP {
+    fn short_description(&self) -> String {
+        if self.is_echo() {
+            return format!(
+                "{}: {} id {} seq {}",
+                self.protocol,
+                self.message_type_name(),
+                self.rest_of_header >> 16,
+                self.rest_of_header & 0xFFFF
+            );
+        }
+
+        format!("{}: {} (type {} code {})", self.protocol, self.message_type_name(), self.message_type, self.code)
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let prefix = self.field_prefix();
+        let mut fields = vec![
+            PacketField::new(format!("{}.type", prefix).as_str(), "Type", self.message_type, 0, 1).with_meaning(self.message_type_name()),
+            PacketField::new(format!("{}.code", prefix).as_str(), "Code", self.code, 1, 1),
+            PacketField::hex(format!("{}.checksum", prefix).as_str(), "Checksum", self.checksum, 2, 2).with_meaning(self.checksum_status),
+        ];
+
+        if self.is_echo() {
+            fields.push(PacketField::new(format!("{}.ident", prefix).as_str(), "Identifier", (self.rest_of_header >> 16) as u16, 4, 2));
+            fields.push(PacketField::new(format!("{}.seq", prefix).as_str(), "Sequence number", (self.rest_of_header & 0xFFFF) as u16, 6, 2));
+        } else {
+            fields.push(PacketField::hex(format!("{}.rest", prefix).as_str(), "Rest of header", self.rest_of_header, 4, 4));
+        }
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn checksum_status(&self) -> Option<ChecksumStatus> {
+        Some(self.checksum_status)
+    }
+
+    // Network cards do not offload ICMP checksums, so they are verified regardless of the offload setting
+    fn verify_checksum(&mut self, addresses: Option<(&[u8], &[u8])>, _offload: bool) {
+        self.checksum_status = match (self.protocol, addresses) {
+            (Protocol::ICMPv6, Some((source, destination))) => checksum_status(
+                self.message_sum + pseudo_header_sum(source, destination, ICMP::IPV6_NEXT_HEADER, self.message_length),
+                self.checksum,
+                false
+            ),
+            (Protocol::ICMP, Some(_)) => checksum_status(self.message_sum, self.checksum, false),
+            _ => ChecksumStatus::Unverified(CHECKSUM_PARTIAL)
+        };
+    }
+}
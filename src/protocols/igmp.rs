@@ -0,0 +1,231 @@
+use std::any::Any;
+use std::net::Ipv4Addr;
+
+use crate::enums::address::Address;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+struct GroupRecord {
+    record_type: u8,
+    group: Ipv4Addr,
+    sources: Vec<Ipv4Addr>,
+    auxiliary_length: usize,
+}
+
+pub struct IGMP {
+    version: u8,
+    message_type: u8,
+    max_response_time: u8,
+    checksum: u16,
+    group: Ipv4Addr,
+    sources: Vec<Ipv4Addr>,
+    records: Vec<GroupRecord>,
+    payload: FrameBuffer
+}
+
+impl IGMP {
+
+    const PROTOCOL: Protocol = Protocol::IGMP;
+    const HEADER_LENGTH: usize = 8;
+    const V3_QUERY_LENGTH: usize = 12;
+
+    const MEMBERSHIP_QUERY: u8 = 0x11;
+    const V1_MEMBERSHIP_REPORT: u8 = 0x12;
+    const V2_MEMBERSHIP_REPORT: u8 = 0x16;
+    const V2_LEAVE_GROUP: u8 = 0x17;
+    const V3_MEMBERSHIP_REPORT: u8 = 0x22;
+
+    const MODE_IS_INCLUDE: u8 = 1;
+    const MODE_IS_EXCLUDE: u8 = 2;
+    const CHANGE_TO_INCLUDE_MODE: u8 = 3;
+    const CHANGE_TO_EXCLUDE_MODE: u8 = 4;
+    const ALLOW_NEW_SOURCES: u8 = 5;
+    const BLOCK_OLD_SOURCES: u8 = 6;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..IGMP::HEADER_LENGTH)?;
+
+        let mut igmp = IGMP {
+            version: 2,
+            message_type: header[0],
+            max_response_time: header[1],
+            checksum: u16::from_be_bytes([header[2], header[3]]),
+            group: Ipv4Addr::new(header[4], header[5], header[6], header[7]),
+            sources: Vec::new(),
+            records: Vec::new(),
+            payload: FrameBuffer::default()
+        };
+
+        let mut offset = IGMP::HEADER_LENGTH;
+
+        match igmp.message_type {
+            IGMP::MEMBERSHIP_QUERY if packet_data.len() >= IGMP::V3_QUERY_LENGTH => {
+                let source_count = u16::from_be_bytes([packet_data[10], packet_data[11]]) as usize;
+
+                igmp.version = 3;
+                igmp.sources = IGMP::parse_addresses(packet_data.get(IGMP::V3_QUERY_LENGTH..)?, source_count);
+                offset = IGMP::V3_QUERY_LENGTH + igmp.sources.len() * 4;
+            },
+            IGMP::MEMBERSHIP_QUERY if igmp.max_response_time == 0 => igmp.version = 1,
+            IGMP::V1_MEMBERSHIP_REPORT => igmp.version = 1,
+            IGMP::V3_MEMBERSHIP_REPORT => {
+                let record_count = u16::from_be_bytes([header[6], header[7]]) as usize;
+
+                igmp.version = 3;
+                igmp.group = Ipv4Addr::UNSPECIFIED;
+
+                for _ in 0..record_count {
+                    let record_header = match packet_data.get(offset..offset + 8) {
+                        Some(record_header) => record_header,
+                        None => break
+                    };
+                    let source_count = u16::from_be_bytes([record_header[2], record_header[3]]) as usize;
+                    let sources = IGMP::parse_addresses(&packet_data[offset + 8..], source_count);
+
+                    igmp.records.push(GroupRecord {
+                        record_type: record_header[0],
+                        group: Ipv4Addr::new(record_header[4], record_header[5], record_header[6], record_header[7]),
+                        sources,
+                        auxiliary_length: record_header[1] as usize * 4,
+                    });
+
+                    offset = (offset + 8 + source_count * 4 + record_header[1] as usize * 4).min(packet_data.len());
+                }
+            },
+            IGMP::V2_MEMBERSHIP_REPORT | IGMP::V2_LEAVE_GROUP | IGMP::MEMBERSHIP_QUERY => {},
+            _ => return None
+        }
+
+        igmp.payload = packet_data.slice(offset.min(packet_data.len())..);
+
+        Some(Box::new(igmp))
+    }
+
+    pub fn membership_changes(&self) -> Vec<(Ipv4Addr, bool)> {
+        match self.message_type {
+            IGMP::V1_MEMBERSHIP_REPORT | IGMP::V2_MEMBERSHIP_REPORT => vec![(self.group, true)],
+            IGMP::V2_LEAVE_GROUP => vec![(self.group, false)],
+            IGMP::V3_MEMBERSHIP_REPORT => self.records
+                .iter()
+                .filter_map(|record| match record.record_type {
+                    IGMP::MODE_IS_EXCLUDE | IGMP::CHANGE_TO_EXCLUDE_MODE | IGMP::ALLOW_NEW_SOURCES => Some((record.group, true)),
+                    IGMP::MODE_IS_INCLUDE if !record.sources.is_empty() => Some((record.group, true)),
+                    IGMP::CHANGE_TO_INCLUDE_MODE if record.sources.is_empty() => Some((record.group, false)),
+                    IGMP::CHANGE_TO_INCLUDE_MODE => Some((record.group, true)),
+                    _ => None
+                })
+                .collect(),
+            _ => Vec::new()
+        }
+    }
+
+    fn parse_addresses(data: &[u8], count: usize) -> Vec<Ipv4Addr> {
+        data.chunks_exact(4)
+            .take(count)
+            .map(|address| Ipv4Addr::new(address[0], address[1], address[2], address[3]))
+            .collect()
+    }
+
+    fn message_type_name(&self) -> &'static str {
+        match self.message_type {
+            IGMP::MEMBERSHIP_QUERY if self.group.is_unspecified() => "General query",
+            IGMP::MEMBERSHIP_QUERY => "Group-specific query",
+            IGMP::V1_MEMBERSHIP_REPORT | IGMP::V2_MEMBERSHIP_REPORT | IGMP::V3_MEMBERSHIP_REPORT => "Membership report",
+            IGMP::V2_LEAVE_GROUP => "Leave group",
+            _ => "Unknown"
+        }
+    }
+
+    fn record_type_name(record_type: u8) -> &'static str {
+        match record_type {
+            IGMP::MODE_IS_INCLUDE => "MODE_IS_INCLUDE",
+            IGMP::MODE_IS_EXCLUDE => "MODE_IS_EXCLUDE",
+            IGMP::CHANGE_TO_INCLUDE_MODE => "CHANGE_TO_INCLUDE_MODE",
+            IGMP::CHANGE_TO_EXCLUDE_MODE => "CHANGE_TO_EXCLUDE_MODE",
+            IGMP::ALLOW_NEW_SOURCES => "ALLOW_NEW_SOURCES",
+            IGMP::BLOCK_OLD_SOURCES => "BLOCK_OLD_SOURCES",
+            _ => "Unknown"
+        }
+    }
+}
+
+impl PacketAnalysis for IGMP {
+    fn short_description(&self) -> String {
+        match self.message_type {
+            IGMP::V3_MEMBERSHIP_REPORT => format!("IGMPv3: {} ({} group records)", self.message_type_name(), self.records.len()),
+            _ if self.group.is_unspecified() => format!("IGMPv{}: {}", self.version, self.message_type_name()),
+            _ => format!("IGMPv{}: {} {}", self.version, self.message_type_name(), self.group)
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::generated("igmp.version", "Version", self.version),
+            PacketField::hex("igmp.type", "Type", self.message_type, 0, 1).with_meaning(self.message_type_name()),
+            PacketField::new("igmp.max_resp", "Max response time", self.max_response_time, 1, 1),
+            PacketField::hex("igmp.checksum", "Checksum", self.checksum, 2, 2),
+        ];
+
+        if self.message_type != IGMP::V3_MEMBERSHIP_REPORT {
+            fields.push(PacketField::new("igmp.maddr", "Group address", self.group, 4, 4));
+        }
+        for (idx, source) in self.sources.iter().enumerate() {
+            fields.push(PacketField::new("igmp.saddr", "Source", *source, IGMP::V3_QUERY_LENGTH + idx * 4, 4));
+        }
+
+        let mut offset = IGMP::HEADER_LENGTH;
+
+        for record in &self.records {
+            let length = 8 + record.sources.len() * 4 + record.auxiliary_length;
+            let mut children = vec![
+                PacketField::new("igmp.record_type", "Record type", record.record_type, offset, 1)
+                    .with_meaning(IGMP::record_type_name(record.record_type)),
+                PacketField::new("igmp.maddr", "Group address", record.group, offset + 4, 4),
+            ];
+
+            for (idx, source) in record.sources.iter().enumerate() {
+                children.push(PacketField::new("igmp.saddr", "Source", *source, offset + 8 + idx * 4, 4));
+            }
+
+            fields.push(PacketField::group("igmp.record", "Group record", offset, length, children));
+            offset += length;
+        }
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        IGMP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        format!("{}", self.group)
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::IPv4(self.group))
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,13 +1,42 @@
+use std::any::Any;
 use std::net::Ipv4Addr;
 
 use pnet::packet::ip::IpNextHeaderProtocol;
 use pnet::packet::ipv4::Ipv4Packet;
 use pnet::packet::Packet;
+use crate::enums::address::Address;
+use crate::enums::checksum_status::ChecksumStatus;
 use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::ip_fragment::IpFragment;
+use crate::models::packet_field::PacketField;
 
-use crate::protocols::tcp::TCP;
-use crate::protocols::udp::UDP;
+use crate::protocols::malformed::{Malformed, MalformedReason};
+use crate::services::dissector_registry::{DissectorKey, DissectorRegistry};
 use crate::traits::packet_analysis::PacketAnalysis;
+use crate::utils::checksum_util::{checksum_status, sum_words};
+
+enum IPv4OptionData {
+    None,
+    Route {
+        pointer: u8,
+        addresses: Vec<Ipv4Addr>,
+    },
+    Timestamp {
+        pointer: u8,
+        overflow: u8,
+        flag: u8,
+        entries: Vec<(Option<Ipv4Addr>, u32)>,
+    },
+    RouterAlert(u16),
+    Raw(Vec<u8>),
+}
+
+struct IPv4Option {
+    option_type: u8,
+    length: usize,
+    data: IPv4OptionData,
+}
 
 pub struct IPv4 {
     source: Ipv4Addr,
@@ -18,19 +47,45 @@ pub struct IPv4 {
     total_length: u16,
     identification: u16,
     flags: u8,
+    fragment_offset: u16,
+    options: Vec<IPv4Option>,
     ttl: u8,
     checksum: u16,
+    header_sum: u32,
+    checksum_status: ChecksumStatus,
     next_level_protocol: IpNextHeaderProtocol,
-    payload: Vec<u8>
+    payload: FrameBuffer
 }
 
 impl IPv4 {
 
     const PROTOCOL: Protocol = Protocol::IPv4;
+    const MIN_HEADER_LENGTH: usize = 20;
+    const FLAG_DONT_FRAGMENT: u8 = 0b010;
+    const FLAG_MORE_FRAGMENTS: u8 = 0b001;
+
+    const OPTION_END_OF_LIST: u8 = 0;
+    const OPTION_NO_OPERATION: u8 = 1;
+    const OPTION_RECORD_ROUTE: u8 = 7;
+    const OPTION_TIMESTAMP: u8 = 68;
+    const OPTION_LOOSE_SOURCE_ROUTE: u8 = 131;
+    const OPTION_STRICT_SOURCE_ROUTE: u8 = 137;
+    const OPTION_ROUTER_ALERT: u8 = 148;
 
-    pub fn new(packet_data: &[u8]) -> Option<Box<dyn PacketAnalysis>> {
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
         match Ipv4Packet::new(packet_data) {
             Some(ipv4) => {
+                let header_length = ipv4.get_header_length() as usize * 4;
+                let total_length = ipv4.get_total_length() as usize;
+
+                if header_length < IPv4::MIN_HEADER_LENGTH
+                    || header_length > packet_data.len()
+                    || (total_length != 0 && total_length < header_length) {
+                    return Malformed::new(Protocol::IPv4, MalformedReason::BadHeaderLength(header_length), 0, packet_data);
+                }
+
+                let header_sum = sum_words(&packet_data[..header_length]);
+
                 Some(Box::new(IPv4 {
                     source: ipv4.get_source(),
                     destination: ipv4.get_destination(),
@@ -40,14 +95,186 @@ impl IPv4 {
                     total_length: ipv4.get_total_length(),
                     identification: ipv4.get_identification(),
                     flags: ipv4.get_flags(),
+                    fragment_offset: ipv4.get_fragment_offset() * 8,
+                    options: IPv4::parse_options(ipv4.get_options_raw()),
                     ttl: ipv4.get_ttl(),
                     checksum: ipv4.get_checksum(),
+                    header_sum,
+                    checksum_status: checksum_status(header_sum, ipv4.get_checksum(), false),
                     next_level_protocol: ipv4.get_next_level_protocol(),
-                    payload: ipv4.payload().to_vec()
+                    payload: packet_data.slice(header_length..header_length + ipv4.payload().len())
                 }))
             },
-            None => None
+            None => Malformed::new(
+                Protocol::IPv4,
+                MalformedReason::TooShort { required: 20, available: packet_data.len() },
+                packet_data.len(),
+                packet_data
+            )
+        }
+    }
+
+    pub fn dissect_next_protocol(next_level_protocol: IpNextHeaderProtocol, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        DissectorRegistry::get().dissect(DissectorKey::IpProtocol(next_level_protocol.0), payload)
+    }
+
+    fn parse_options(options_data: &[u8]) -> Vec<IPv4Option> {
+        let mut options = Vec::new();
+        let mut offset = 0;
+
+        while offset < options_data.len() {
+            let option_type = options_data[offset];
+
+            if option_type == IPv4::OPTION_END_OF_LIST || option_type == IPv4::OPTION_NO_OPERATION {
+                options.push(IPv4Option { option_type, length: 1, data: IPv4OptionData::None });
+                offset += 1;
+
+                if option_type == IPv4::OPTION_END_OF_LIST {
+                    break;
+                }
+                continue;
+            }
+
+            let option_length = match options_data.get(offset + 1) {
+                Some(length) if *length >= 2 && offset + *length as usize <= options_data.len() => *length as usize,
+                _ => break
+            };
+
+            let value = &options_data[offset + 2..offset + option_length];
+
+            options.push(IPv4Option {
+                option_type,
+                length: option_length,
+                data: IPv4::parse_option_data(option_type, value),
+            });
+
+            offset += option_length;
+        }
+
+        options
+    }
+
+    fn parse_option_data(option_type: u8, value: &[u8]) -> IPv4OptionData {
+        match option_type {
+            IPv4::OPTION_RECORD_ROUTE | IPv4::OPTION_LOOSE_SOURCE_ROUTE | IPv4::OPTION_STRICT_SOURCE_ROUTE if !value.is_empty() => {
+                IPv4OptionData::Route {
+                    pointer: value[0],
+                    addresses: value[1..]
+                        .chunks_exact(4)
+                        .map(|chunk| Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3]))
+                        .collect(),
+                }
+            },
+            IPv4::OPTION_TIMESTAMP if value.len() >= 2 => {
+                let flag = value[1] & 0x0F;
+                let entry_length = if flag == 0 { 4 } else { 8 };
+
+                IPv4OptionData::Timestamp {
+                    pointer: value[0],
+                    overflow: value[1] >> 4,
+                    flag,
+                    entries: value[2..]
+                        .chunks_exact(entry_length)
+                        .map(|chunk| {
+                            if flag == 0 {
+                                (None, u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                            } else {
+                                (
+                                    Some(Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3])),
+                                    u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]])
+                                )
+                            }
+                        })
+                        .collect(),
+                }
+            },
+            IPv4::OPTION_ROUTER_ALERT if value.len() >= 2 => IPv4OptionData::RouterAlert(u16::from_be_bytes([value[0], value[1]])),
+            _ => IPv4OptionData::Raw(value.to_vec())
+        }
+    }
+
+    fn option_name(option_type: u8) -> &'static str {
+        match option_type {
+            IPv4::OPTION_END_OF_LIST => "EOL",
+            IPv4::OPTION_NO_OPERATION => "NOP",
+            IPv4::OPTION_RECORD_ROUTE => "RR",
+            IPv4::OPTION_TIMESTAMP => "TS",
+            IPv4::OPTION_LOOSE_SOURCE_ROUTE => "LSRR",
+            IPv4::OPTION_STRICT_SOURCE_ROUTE => "SSRR",
+            IPv4::OPTION_ROUTER_ALERT => "RA",
+            130 => "SEC",
+            136 => "SID",
+            _ => "UNKNOWN"
+        }
+    }
+
+    fn dscp_name(dscp: u8) -> String {
+        match dscp {
+            0 => String::from("CS0"),
+            1 => String::from("LE"),
+            44 => String::from("VA"),
+            46 => String::from("EF"),
+            _ if dscp.is_multiple_of(8) => format!("CS{}", dscp / 8),
+            _ if dscp.is_multiple_of(2) && (1..=3).contains(&((dscp % 8) / 2)) && (1..=4).contains(&(dscp / 8)) => {
+                format!("AF{}{}", dscp / 8, (dscp % 8) / 2)
+            },
+            _ => format!("{}", dscp)
+        }
+    }
+
+    fn ecn_name(ecn: u8) -> &'static str {
+        match ecn {
+            0 => "Not-ECT",
+            1 => "ECT(1)",
+            2 => "ECT(0)",
+            _ => "CE"
+        }
+    }
+
+    fn is_fragment(&self) -> bool {
+        self.flags & IPv4::FLAG_MORE_FRAGMENTS != 0 || self.fragment_offset > 0
+    }
+
+    fn option_field(option: &IPv4Option, offset: usize) -> PacketField {
+        let mut children = Vec::new();
+
+        match &option.data {
+            IPv4OptionData::None => {},
+            IPv4OptionData::Route { pointer, addresses } => {
+                children.push(PacketField::new("ip.opt.ptr", "Pointer", *pointer, offset + 2, 1));
+                for (idx, address) in addresses.iter().enumerate() {
+                    children.push(PacketField::new("ip.opt.addr", "Address", *address, offset + 3 + idx * 4, 4));
+                }
+            },
+            IPv4OptionData::Timestamp { pointer, overflow, flag, entries } => {
+                children.push(PacketField::new("ip.opt.ptr", "Pointer", *pointer, offset + 2, 1));
+                children.push(PacketField::new("ip.opt.ovflw", "Overflow", *overflow, offset + 3, 1));
+                children.push(PacketField::new("ip.opt.flag", "Flag", *flag, offset + 3, 1));
+
+                let mut entry_offset = offset + 4;
+
+                for (address, timestamp) in entries {
+                    if let Some(address) = address {
+                        children.push(PacketField::new("ip.opt.addr", "Address", *address, entry_offset, 4));
+                        entry_offset += 4;
+                    }
+                    children.push(
+                        PacketField::new("ip.opt.time_stamp", "Timestamp", *timestamp, entry_offset, 4).with_meaning("ms")
+                    );
+                    entry_offset += 4;
+                }
+            },
+            IPv4OptionData::RouterAlert(value) => {
+                children.push(PacketField::new("ip.opt.ra", "Value", *value, offset + 2, 2));
+            },
+            IPv4OptionData::Raw(data) => {
+                children.push(PacketField::new("ip.opt.data", "Data", data.as_slice(), offset + 2, data.len()));
+            }
         }
+
+        PacketField::new("ip.opt.type", "Option", option.option_type, offset, option.length)
+            .with_meaning(IPv4::option_name(option.option_type))
+            .with_children(children)
     }
 }
 
@@ -57,29 +284,62 @@ impl PacketAnalysis for IPv4 {
     }
 
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
-        match self.next_level_protocol {
-            IpNextHeaderProtocol(6) => TCP::new(self.payload.as_slice()),
-            IpNextHeaderProtocol(17) => UDP::new(self.payload.as_slice()),
-            _ => None
+        let header_length = self.header_length as usize * 4;
+
+        if self.total_length as usize > header_length + self.payload.len() {
+            return Malformed::new(
+                Protocol::IPv4,
+                MalformedReason::TotalLengthExceedsCapture {
+                    declared: self.total_length as usize,
+                    captured: header_length + self.payload.len()
+                },
+                self.payload.len(),
+                &self.payload
+            );
         }
+
+        if self.fragment_offset > 0 {
+            return None;
+        }
+
+        IPv4::dissect_next_protocol(self.next_level_protocol, &self.payload)
     }
 
-    fn print_details(&self) {
-        println!("-------- IPv4 packet --------");
-        println!("|");
-        println!("| Source: {}", self.source);
-        println!("| Destination: {}", self.destination);
-        println!("| Header length: {}", self.header_length);
-        println!("| DSCP: {}", self.dscp);
-        println!("| ECN: {}", self.ecn);
-        println!("| Total length: {}", self.total_length);
-        println!("| Identification: {}", self.identification);
-        println!("| Flags: {}", self.flags);
-        println!("| TTL: {}", self.ttl);
-        println!("| Checksum: {}", self.checksum);
-        println!("| Next level protocol: {:?}", self.next_level_protocol);
-        println!("| Payload length: {}", self.payload.len());
-        println!("|");
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("ip.version", "Version", 4u8, 0, 1),
+            PacketField::new("ip.hdr_len", "Header length", self.header_length, 0, 1),
+            PacketField::new("ip.dsfield.dscp", "DSCP", self.dscp, 1, 1).with_meaning(IPv4::dscp_name(self.dscp)),
+            PacketField::new("ip.dsfield.ecn", "ECN", self.ecn, 1, 1).with_meaning(IPv4::ecn_name(self.ecn)),
+            PacketField::new("ip.len", "Total length", self.total_length, 2, 2),
+            PacketField::new("ip.id", "Identification", self.identification, 4, 2),
+            PacketField::new("ip.flags", "Flags", self.flags, 6, 1).with_children(vec![
+                PacketField::new("ip.flags.df", "Don't fragment", self.flags & IPv4::FLAG_DONT_FRAGMENT != 0, 6, 1),
+                PacketField::new("ip.flags.mf", "More fragments", self.flags & IPv4::FLAG_MORE_FRAGMENTS != 0, 6, 1),
+            ]),
+            PacketField::new("ip.frag_offset", "Fragment offset", self.fragment_offset, 6, 2),
+            PacketField::new("ip.ttl", "TTL", self.ttl, 8, 1),
+            PacketField::new("ip.proto", "Next level protocol", self.next_level_protocol.0, 9, 1).with_meaning(self.next_level_protocol),
+            PacketField::hex("ip.checksum", "Checksum", self.checksum, 10, 2).with_meaning(self.checksum_status),
+            PacketField::new("ip.src", "Source", self.source, 12, 4),
+            PacketField::new("ip.dst", "Destination", self.destination, 16, 4),
+        ];
+
+        if !self.options.is_empty() {
+            let mut offset = IPv4::MIN_HEADER_LENGTH;
+            let mut children = Vec::new();
+
+            for option in &self.options {
+                children.push(IPv4::option_field(option, offset));
+                offset += option.length;
+            }
+
+            fields.push(PacketField::group("ip.options", "Options", IPv4::MIN_HEADER_LENGTH, offset - IPv4::MIN_HEADER_LENGTH, children));
+        }
+
+        fields.push(PacketField::generated("ip.payload_length", "Payload length", self.payload.len()));
+
+        fields
     }
 
     fn protocol(&self) -> Protocol {
@@ -94,11 +354,67 @@ impl PacketAnalysis for IPv4 {
         format!("{}", self.destination)
     }
 
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::IPv4(self.source))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::IPv4(self.destination))
+    }
+
     fn payload_length(&self) -> usize {
         self.payload.len()
     }
 
-    fn payload(&self) -> &Vec<u8> {
-        self.payload.as_ref()
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> Vec<String> {
+        let mut properties = vec![IPv4::dscp_name(self.dscp), String::from(IPv4::ecn_name(self.ecn))];
+
+        if self.flags & IPv4::FLAG_DONT_FRAGMENT != 0 {
+            properties.push(String::from("DF"));
+        }
+
+        if self.flags & IPv4::FLAG_MORE_FRAGMENTS != 0 {
+            properties.push(String::from("MF"));
+        }
+
+        if self.is_fragment() {
+            properties.push(String::from("FRAGMENT"));
+        }
+
+        for option in &self.options {
+            properties.push(String::from(IPv4::option_name(option.option_type)));
+        }
+
+        properties
+    }
+
+    fn checksum_status(&self) -> Option<ChecksumStatus> {
+        Some(self.checksum_status)
+    }
+
+    fn verify_checksum(&mut self, _addresses: Option<(&[u8], &[u8])>, offload: bool) {
+        self.checksum_status = checksum_status(self.header_sum, self.checksum, offload);
+    }
+
+    fn fragment(&self) -> Option<IpFragment> {
+        if !self.is_fragment() {
+            return None;
+        }
+
+        Some(IpFragment {
+            identification: self.identification as u32,
+            offset: self.fragment_offset as usize,
+            more_fragments: self.flags & IPv4::FLAG_MORE_FRAGMENTS != 0,
+            next_protocol: self.next_level_protocol,
+            data: self.payload.clone(),
+        })
     }
 }
\ No newline at end of file
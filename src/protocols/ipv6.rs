@@ -1,12 +1,16 @@
+use std::any::Any;
 use std::net::Ipv6Addr;
 
 use pnet::packet::ip::IpNextHeaderProtocol;
 use pnet::packet::ipv6::Ipv6Packet;
 use pnet::packet::Packet;
+use crate::enums::address::Address;
 use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
 
-use crate::protocols::tcp::TCP;
-use crate::protocols::udp::UDP;
+use crate::protocols::malformed::{Malformed, MalformedReason};
+use crate::services::dissector_registry::DissectorRegistry;
 use crate::traits::packet_analysis::PacketAnalysis;
 
 pub struct IPv6 {
@@ -17,16 +21,19 @@ pub struct IPv6 {
     payload_length: u16,
     next_header: IpNextHeaderProtocol,
     hop_limit: u8,
-    payload: Vec<u8>
+    payload: FrameBuffer
 }
 
 impl IPv6 {
 
     const PROTOCOL: Protocol = Protocol::IPv6;
+    const HEADER_LENGTH: usize = 40;
 
-    pub fn new(packet_data: &[u8]) -> Option<Box<dyn PacketAnalysis>> {
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
         match Ipv6Packet::new(packet_data) {
             Some(ipv6) => {
+                let header_length = Ipv6Packet::minimum_packet_size();
+
                 Some(Box::new(IPv6 {
                     source: ipv6.get_source(),
                     destination: ipv6.get_destination(),
@@ -35,12 +42,21 @@ impl IPv6 {
                     payload_length: ipv6.get_payload_length(),
                     next_header: ipv6.get_next_header(),
                     hop_limit: ipv6.get_hop_limit(),
-                    payload: ipv6.payload().to_vec()
+                    payload: packet_data.slice(header_length..header_length + ipv6.payload().len())
                 }))
             },
-            None => None
+            None => Malformed::new(
+                Protocol::IPv6,
+                MalformedReason::TooShort { required: 40, available: packet_data.len() },
+                packet_data.len(),
+                packet_data
+            )
         }
     }
+
+    pub fn dissect_next_header(next_header: IpNextHeaderProtocol, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        DissectorRegistry::get().dissect_ipv6_next_header(next_header.0, payload)
+    }
 }
 
 impl PacketAnalysis for IPv6 {
@@ -49,24 +65,32 @@ impl PacketAnalysis for IPv6 {
     }
 
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
-        match self.next_header {
-            IpNextHeaderProtocol(6) => TCP::new(self.payload.as_slice()),
-            IpNextHeaderProtocol(17) => UDP::new(self.payload.as_slice()),
-            _ => None
+        if self.payload_length as usize > self.payload.len() {
+            return Malformed::new(
+                Protocol::IPv6,
+                MalformedReason::TotalLengthExceedsCapture {
+                    declared: IPv6::HEADER_LENGTH + self.payload_length as usize,
+                    captured: IPv6::HEADER_LENGTH + self.payload.len()
+                },
+                self.payload.len(),
+                &self.payload
+            );
         }
+
+        IPv6::dissect_next_header(self.next_header, &self.payload)
     }
 
-    fn print_details(&self) {
-        println!("--------- IPv6 packet ---------");
-        println!("|");
-        println!("| Source: {}", self.source);
-        println!("| Destination: {}", self.destination);
-        println!("| Traffic class: {}", self.traffic_class);
-        println!("| Flow label: {}", self.flow_label);
-        println!("| Payload length: {}", self.payload_length);
-        println!("| Next header: {:?}", self.next_header);
-        println!("| Hop limit: {}", self.hop_limit);
-        println!("|");
+    fn fields(&self) -> Vec<PacketField> {
+        vec![
+            PacketField::new("ipv6.version", "Version", 6u8, 0, 1),
+            PacketField::new("ipv6.tclass", "Traffic class", self.traffic_class, 0, 2),
+            PacketField::new("ipv6.flow", "Flow label", self.flow_label, 1, 3),
+            PacketField::new("ipv6.plen", "Payload length", self.payload_length, 4, 2),
+            PacketField::new("ipv6.nxt", "Next header", self.next_header.0, 6, 1).with_meaning(self.next_header),
+            PacketField::new("ipv6.hlim", "Hop limit", self.hop_limit, 7, 1),
+            PacketField::new("ipv6.src", "Source", self.source, 8, 16),
+            PacketField::new("ipv6.dst", "Destination", self.destination, 24, 16),
+        ]
     }
 
     fn protocol(&self) -> Protocol {
@@ -81,11 +105,23 @@ impl PacketAnalysis for IPv6 {
         format!("{}", self.destination)
     }
 
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::IPv6(self.source))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::IPv6(self.destination))
+    }
+
     fn payload_length(&self) -> usize {
         self.payload.len()
     }
 
-    fn payload(&self) -> &Vec<u8> {
-        self.payload.as_ref()
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 }
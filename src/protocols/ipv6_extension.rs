@@ -0,0 +1,339 @@
+use std::any::Any;
+use std::net::Ipv6Addr;
+
+use pnet::packet::ip::IpNextHeaderProtocol;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::ip_fragment::IpFragment;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::ipv6::IPv6;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+struct IPv6Option {
+    option_type: u8,
+    data: Vec<u8>,
+}
+
+enum ExtensionFields {
+    Options(Vec<IPv6Option>),
+    Routing {
+        routing_type: u8,
+        segments_left: u8,
+        addresses: Vec<Ipv6Addr>,
+    },
+    Fragment {
+        fragment_offset: u16,
+        more_fragments: bool,
+        identification: u32,
+    },
+    AuthenticationHeader {
+        security_parameters_index: u32,
+        sequence: u32,
+        integrity_check_value: Vec<u8>,
+    },
+    EncapsulatingSecurityPayload {
+        security_parameters_index: u32,
+        sequence: u32,
+    },
+}
+
+pub struct IPv6Extension {
+    protocol: Protocol,
+    next_header: Option<IpNextHeaderProtocol>,
+    header_length: usize,
+    fields: ExtensionFields,
+    payload: FrameBuffer
+}
+
+impl IPv6Extension {
+
+    const FRAGMENT_HEADER_LENGTH: usize = 8;
+    const ESP_HEADER_LENGTH: usize = 8;
+
+    pub fn new(protocol: Protocol, packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let extension = match protocol {
+            Protocol::IPv6HopByHop | Protocol::IPv6DestinationOptions => IPv6Extension::parse_options(protocol, packet_data),
+            Protocol::IPv6Routing => IPv6Extension::parse_routing(packet_data),
+            Protocol::IPv6Fragment => IPv6Extension::parse_fragment(packet_data),
+            Protocol::AH => IPv6Extension::parse_authentication_header(packet_data),
+            Protocol::ESP => IPv6Extension::parse_encapsulating_security_payload(packet_data),
+            _ => None
+        };
+
+        match extension {
+            Some(extension) => Some(Box::new(extension)),
+            None => None
+        }
+    }
+
+    fn parse_options(protocol: Protocol, packet_data: &FrameBuffer) -> Option<IPv6Extension> {
+        let header_length = (*packet_data.get(1)? as usize + 1) * 8;
+        let header = packet_data.get(..header_length)?;
+        let mut options = Vec::new();
+        let mut offset = 2;
+
+        while offset < header.len() {
+            let option_type = header[offset];
+
+            if option_type == 0 {
+                options.push(IPv6Option { option_type, data: Vec::new() });
+                offset += 1;
+                continue;
+            }
+
+            let option_length = *header.get(offset + 1)? as usize;
+            let data = header.get(offset + 2..offset + 2 + option_length)?.to_vec();
+
+            options.push(IPv6Option { option_type, data });
+            offset += 2 + option_length;
+        }
+
+        Some(IPv6Extension {
+            protocol,
+            next_header: Some(IpNextHeaderProtocol(packet_data[0])),
+            header_length,
+            fields: ExtensionFields::Options(options),
+            payload: packet_data.slice(header_length..)
+        })
+    }
+
+    fn parse_routing(packet_data: &FrameBuffer) -> Option<IPv6Extension> {
+        let header_length = (*packet_data.get(1)? as usize + 1) * 8;
+        let header = packet_data.get(..header_length)?;
+        let routing_type = *header.get(2)?;
+        let segments_left = *header.get(3)?;
+
+        let addresses = match routing_type {
+            0 | 2 | 4 => header[8..]
+                .chunks_exact(16)
+                .map(|chunk| {
+                    let mut octets = [0u8; 16];
+                    octets.copy_from_slice(chunk);
+                    Ipv6Addr::from(octets)
+                })
+                .collect(),
+            _ => Vec::new()
+        };
+
+        Some(IPv6Extension {
+            protocol: Protocol::IPv6Routing,
+            next_header: Some(IpNextHeaderProtocol(packet_data[0])),
+            header_length,
+            fields: ExtensionFields::Routing { routing_type, segments_left, addresses },
+            payload: packet_data.slice(header_length..)
+        })
+    }
+
+    fn parse_fragment(packet_data: &FrameBuffer) -> Option<IPv6Extension> {
+        let header = packet_data.get(..IPv6Extension::FRAGMENT_HEADER_LENGTH)?;
+        let offset_and_flags = u16::from_be_bytes([header[2], header[3]]);
+
+        Some(IPv6Extension {
+            protocol: Protocol::IPv6Fragment,
+            next_header: Some(IpNextHeaderProtocol(header[0])),
+            header_length: IPv6Extension::FRAGMENT_HEADER_LENGTH,
+            fields: ExtensionFields::Fragment {
+                fragment_offset: (offset_and_flags >> 3) * 8,
+                more_fragments: offset_and_flags & 0x0001 != 0,
+                identification: u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+            },
+            payload: packet_data.slice(IPv6Extension::FRAGMENT_HEADER_LENGTH..)
+        })
+    }
+
+    fn parse_authentication_header(packet_data: &FrameBuffer) -> Option<IPv6Extension> {
+        let header_length = (*packet_data.get(1)? as usize + 2) * 4;
+        let header = packet_data.get(..header_length)?;
+
+        if header_length < 12 {
+            return None;
+        }
+
+        Some(IPv6Extension {
+            protocol: Protocol::AH,
+            next_header: Some(IpNextHeaderProtocol(header[0])),
+            header_length,
+            fields: ExtensionFields::AuthenticationHeader {
+                security_parameters_index: u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+                sequence: u32::from_be_bytes([header[8], header[9], header[10], header[11]]),
+                integrity_check_value: header[12..].to_vec(),
+            },
+            payload: packet_data.slice(header_length..)
+        })
+    }
+
+    fn parse_encapsulating_security_payload(packet_data: &FrameBuffer) -> Option<IPv6Extension> {
+        let header = packet_data.get(..IPv6Extension::ESP_HEADER_LENGTH)?;
+
+        Some(IPv6Extension {
+            protocol: Protocol::ESP,
+            next_header: None,
+            header_length: IPv6Extension::ESP_HEADER_LENGTH,
+            fields: ExtensionFields::EncapsulatingSecurityPayload {
+                security_parameters_index: u32::from_be_bytes([header[0], header[1], header[2], header[3]]),
+                sequence: u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+            },
+            payload: packet_data.slice(IPv6Extension::ESP_HEADER_LENGTH..)
+        })
+    }
+
+    fn option_name(option_type: u8) -> &'static str {
+        match option_type {
+            0x00 => "Pad1",
+            0x01 => "PadN",
+            0x04 => "Tunnel Encapsulation Limit",
+            0x05 => "Router Alert",
+            0x63 => "RPL Option",
+            0xC2 => "Jumbo Payload",
+            0xC9 => "Home Address",
+            _ => "Unknown"
+        }
+    }
+
+    fn routing_type_name(routing_type: u8) -> &'static str {
+        match routing_type {
+            0 => "Source Route (deprecated)",
+            2 => "Type 2 (Mobile IPv6)",
+            3 => "RPL Source Route",
+            4 => "Segment Routing",
+            _ => "Unknown"
+        }
+    }
+}
+
+impl PacketAnalysis for IPv6Extension {
+    fn short_description(&self) -> String {
+        match &self.fields {
+            ExtensionFields::Options(options) => {
+                format!("{}: {} options", self.protocol, options.len())
+            },
+            ExtensionFields::Routing { routing_type, segments_left, .. } => {
+                format!("{}: type {} segments left {}", self.protocol, routing_type, segments_left)
+            },
+            ExtensionFields::Fragment { fragment_offset, more_fragments, identification } => {
+                format!(
+                    "{}: id 0x{:08x} offset {}{}",
+                    self.protocol,
+                    identification,
+                    fragment_offset,
+                    if *more_fragments { " MF" } else { "" }
+                )
+            },
+            ExtensionFields::AuthenticationHeader { security_parameters_index, sequence, .. }
+            | ExtensionFields::EncapsulatingSecurityPayload { security_parameters_index, sequence } => {
+                format!("{}: SPI 0x{:08x} seq {}", self.protocol, security_parameters_index, sequence)
+            }
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        if let ExtensionFields::Fragment { fragment_offset, .. } = self.fields {
+            if fragment_offset > 0 {
+                return None;
+            }
+        }
+
+        match self.next_header {
+            Some(next_header) => IPv6::dissect_next_header(next_header, &self.payload),
+            None => None
+        }
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = Vec::new();
+
+        if let Some(next_header) = self.next_header {
+            fields.push(PacketField::new("ipv6.ext.nxt", "Next header", next_header.0, 0, 1).with_meaning(next_header));
+        }
+
+        fields.push(PacketField::generated("ipv6.ext.len", "Header length", self.header_length));
+
+        match &self.fields {
+            ExtensionFields::Options(options) => {
+                let mut offset = 2;
+                let mut children = Vec::new();
+
+                for option in options {
+                    let length = if option.option_type == 0 { 1 } else { 2 + option.data.len() };
+
+                    children.push(
+                        PacketField::hex("ipv6.opt.type", "Option", option.option_type, offset, 1)
+                            .with_meaning(IPv6Extension::option_name(option.option_type))
+                            .with_children(vec![PacketField::new("ipv6.opt.data", "Data", option.data.as_slice(), offset + 2, option.data.len())])
+                    );
+                    offset += length;
+                }
+
+                fields.push(PacketField::group("ipv6.opt", "Options", 2, offset - 2, children));
+            },
+            ExtensionFields::Routing { routing_type, segments_left, addresses } => {
+                fields.push(
+                    PacketField::new("ipv6.routing.type", "Routing type", *routing_type, 2, 1)
+                        .with_meaning(IPv6Extension::routing_type_name(*routing_type))
+                );
+                fields.push(PacketField::new("ipv6.routing.segleft", "Segments left", *segments_left, 3, 1));
+                for (idx, address) in addresses.iter().enumerate() {
+                    fields.push(PacketField::new("ipv6.routing.addr", "Address", *address, 8 + idx * 16, 16));
+                }
+            },
+            ExtensionFields::Fragment { fragment_offset, more_fragments, identification } => {
+                fields.push(PacketField::new("ipv6.fragment.offset", "Fragment offset", *fragment_offset, 2, 2));
+                fields.push(PacketField::new("ipv6.fragment.more", "More fragments", *more_fragments, 3, 1));
+                fields.push(PacketField::hex("ipv6.fragment.id", "Identification", *identification, 4, 4));
+            },
+            ExtensionFields::AuthenticationHeader { security_parameters_index, sequence, integrity_check_value } => {
+                fields.push(PacketField::hex("ah.spi", "SPI", *security_parameters_index, 4, 4));
+                fields.push(PacketField::new("ah.sequence", "Sequence", *sequence, 8, 4));
+                fields.push(PacketField::new("ah.icv", "ICV", integrity_check_value.as_slice(), 12, integrity_check_value.len()));
+            },
+            ExtensionFields::EncapsulatingSecurityPayload { security_parameters_index, sequence } => {
+                fields.push(PacketField::hex("esp.spi", "SPI", *security_parameters_index, 0, 4));
+                fields.push(PacketField::new("esp.sequence", "Sequence", *sequence, 4, 4));
+            }
+        }
+
+        fields.push(PacketField::generated("ipv6.ext.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn fragment(&self) -> Option<IpFragment> {
+        match (&self.fields, self.next_header) {
+            (ExtensionFields::Fragment { fragment_offset, more_fragments, identification }, Some(next_header)) => {
+                Some(IpFragment {
+                    identification: *identification,
+                    offset: *fragment_offset as usize,
+                    more_fragments: *more_fragments,
+                    next_protocol: next_header,
+                    data: self.payload.clone(),
+                })
+            },
+            _ => None
+        }
+    }
+}
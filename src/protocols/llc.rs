@@ -0,0 +1,116 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::services::dissector_registry::{DissectorKey, DissectorRegistry};
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct LLC {
+    dsap: u8,
+    ssap: u8,
+    control: u16,
+    snap: Option<(u32, u16)>,
+    payload: FrameBuffer
+}
+
+impl LLC {
+
+    const PROTOCOL: Protocol = Protocol::LLC;
+    const SAP_SNAP: u8 = 0xAA;
+    const OUI_ENCAPSULATED_ETHERNET: u32 = 0x000000;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..3)?;
+        let dsap = header[0];
+        let ssap = header[1];
+
+        let (control, mut offset) = if header[2] & 0x03 == 0x03 {
+            (header[2] as u16, 3)
+        } else {
+            (u16::from_le_bytes([header[2], *packet_data.get(3)?]), 4)
+        };
+
+        let snap = if dsap == LLC::SAP_SNAP && ssap == LLC::SAP_SNAP {
+            let snap_header = packet_data.get(offset..offset + 5)?;
+            offset += 5;
+
+            Some((
+                u32::from_be_bytes([0, snap_header[0], snap_header[1], snap_header[2]]),
+                u16::from_be_bytes([snap_header[3], snap_header[4]])
+            ))
+        } else {
+            None
+        };
+
+        Some(Box::new(LLC {
+            dsap,
+            ssap,
+            control,
+            snap,
+            payload: packet_data.slice(offset..)
+        }))
+    }
+}
+
+impl PacketAnalysis for LLC {
+    fn short_description(&self) -> String {
+        match self.snap {
+            Some((oui, pid)) => format!("LLC/SNAP: OUI 0x{:06x} PID 0x{:04x} len {}", oui, pid, self.payload.len()),
+            None => format!("LLC: DSAP 0x{:02x} SSAP 0x{:02x} len {}", self.dsap, self.ssap, self.payload.len())
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        let key = match self.snap {
+            Some((LLC::OUI_ENCAPSULATED_ETHERNET, pid)) => DissectorKey::EtherType(pid),
+            Some((oui, pid)) => DissectorKey::Snap(oui, pid),
+            None => DissectorKey::LlcSap(self.dsap)
+        };
+
+        DissectorRegistry::get().dissect(key, &self.payload)
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let control_length = if self.control & 0x03 == 0x03 { 1 } else { 2 };
+
+        let mut fields = vec![
+            PacketField::hex("llc.dsap", "DSAP", self.dsap, 0, 1),
+            PacketField::hex("llc.ssap", "SSAP", self.ssap, 1, 1),
+            PacketField::hex("llc.control", "Control", self.control, 2, control_length),
+        ];
+
+        if let Some((oui, pid)) = self.snap {
+            fields.push(PacketField::hex("llc.oui", "SNAP OUI", oui, 2 + control_length, 3));
+            fields.push(PacketField::hex("llc.pid", "SNAP protocol ID", pid, 5 + control_length, 2));
+        }
+        fields.push(PacketField::generated("llc.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        LLC::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,246 @@
+use std::any::Any;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use pnet::util::MacAddr;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::neighbor::Neighbor;
+use crate::models::packet_field::{FieldValue, PacketField};
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct LLDP {
+    chassis_id: String,
+    port_id: String,
+    ttl: u16,
+    port_description: Option<String>,
+    system_name: Option<String>,
+    system_description: Option<String>,
+    capabilities: Vec<String>,
+    enabled_capabilities: Vec<String>,
+    management_addresses: Vec<String>,
+    vlan: Option<u16>,
+    vlan_offset: Option<usize>,
+    tlvs: Vec<(u8, usize, usize)>,
+    payload: FrameBuffer
+}
+
+impl LLDP {
+
+    const PROTOCOL: Protocol = Protocol::LLDP;
+
+    const TLV_END: u8 = 0;
+    const TLV_CHASSIS_ID: u8 = 1;
+    const TLV_PORT_ID: u8 = 2;
+    const TLV_TTL: u8 = 3;
+    const TLV_PORT_DESCRIPTION: u8 = 4;
+    const TLV_SYSTEM_NAME: u8 = 5;
+    const TLV_SYSTEM_DESCRIPTION: u8 = 6;
+    const TLV_CAPABILITIES: u8 = 7;
+    const TLV_MANAGEMENT_ADDRESS: u8 = 8;
+    const TLV_ORGANIZATION_SPECIFIC: u8 = 127;
+
+    const OUI_IEEE_802_1: [u8; 3] = [0x00, 0x80, 0xC2];
+    const SUBTYPE_PORT_VLAN_ID: u8 = 1;
+
+    const CAPABILITY_NAMES: [&'static str; 11] = [
+        "Other", "Repeater", "Bridge", "WLAN access point", "Router", "Telephone",
+        "DOCSIS cable device", "Station", "C-VLAN", "S-VLAN", "TPMR"
+    ];
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let mut lldp = LLDP {
+            chassis_id: String::new(),
+            port_id: String::new(),
+            ttl: 0,
+            port_description: None,
+            system_name: None,
+            system_description: None,
+            capabilities: Vec::new(),
+            enabled_capabilities: Vec::new(),
+            management_addresses: Vec::new(),
+            vlan: None,
+            vlan_offset: None,
+            tlvs: Vec::new(),
+            payload: FrameBuffer::default()
+        };
+
+        let mut offset = 0;
+
+        while offset + 2 <= packet_data.len() {
+            let header = u16::from_be_bytes([packet_data[offset], packet_data[offset + 1]]);
+            let tlv_type = (header >> 9) as u8;
+            let value = match packet_data.get(offset + 2..offset + 2 + (header & 0x01FF) as usize) {
+                Some(value) => value,
+                None => break
+            };
+
+            lldp.tlvs.push((tlv_type, offset, 2 + value.len()));
+            let tlv_offset = offset;
+            offset += 2 + value.len();
+
+            match tlv_type {
+                LLDP::TLV_END => break,
+                LLDP::TLV_CHASSIS_ID => lldp.chassis_id = LLDP::format_identifier(value, 4),
+                LLDP::TLV_PORT_ID => lldp.port_id = LLDP::format_identifier(value, 3),
+                LLDP::TLV_TTL if value.len() >= 2 => lldp.ttl = u16::from_be_bytes([value[0], value[1]]),
+                LLDP::TLV_PORT_DESCRIPTION => lldp.port_description = Some(LLDP::format_text(value)),
+                LLDP::TLV_SYSTEM_NAME => lldp.system_name = Some(LLDP::format_text(value)),
+                LLDP::TLV_SYSTEM_DESCRIPTION => lldp.system_description = Some(LLDP::format_text(value)),
+                LLDP::TLV_CAPABILITIES if value.len() >= 4 => {
+                    lldp.capabilities = LLDP::capability_names(u16::from_be_bytes([value[0], value[1]]));
+                    lldp.enabled_capabilities = LLDP::capability_names(u16::from_be_bytes([value[2], value[3]]));
+                },
+                LLDP::TLV_MANAGEMENT_ADDRESS => {
+                    if let Some(address) = LLDP::format_management_address(value) {
+                        lldp.management_addresses.push(address);
+                    }
+                },
+                LLDP::TLV_ORGANIZATION_SPECIFIC
+                    if value.len() >= 6 && value[..3] == LLDP::OUI_IEEE_802_1 && value[3] == LLDP::SUBTYPE_PORT_VLAN_ID => {
+                    lldp.vlan = Some(u16::from_be_bytes([value[4], value[5]]));
+                    lldp.vlan_offset = Some(tlv_offset);
+                },
+                _ => {}
+            }
+        }
+
+        lldp.payload = packet_data.slice(offset..);
+
+        Some(Box::new(lldp))
+    }
+
+    pub fn neighbor(&self) -> Neighbor {
+        let mut neighbor = Neighbor::new(LLDP::PROTOCOL, self.chassis_id.clone(), self.port_id.clone());
+
+        neighbor.system_name = self.system_name.clone();
+        neighbor.system_description = self.system_description.clone();
+        neighbor.management_addresses = self.management_addresses.clone();
+        neighbor.vlan = self.vlan;
+        neighbor.capabilities = self.enabled_capabilities.clone();
+
+        neighbor
+    }
+
+    fn format_identifier(value: &[u8], mac_subtype: u8) -> String {
+        match value.split_first() {
+            Some((subtype, id)) if *subtype == mac_subtype && id.len() == 6 => {
+                MacAddr::new(id[0], id[1], id[2], id[3], id[4], id[5]).to_string()
+            },
+            Some((_, id)) => LLDP::format_text(id),
+            None => String::new()
+        }
+    }
+
+    fn format_text(value: &[u8]) -> String {
+        String::from_utf8_lossy(value).trim_end_matches('\0').to_string()
+    }
+
+    fn format_management_address(value: &[u8]) -> Option<String> {
+        let address_length = *value.first()? as usize;
+        let address = value.get(1..1 + address_length)?;
+
+        match address.split_first() {
+            Some((1, ip)) if ip.len() == 4 => Some(Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3]).to_string()),
+            Some((2, ip)) if ip.len() == 16 => {
+                let octets: [u8; 16] = ip.try_into().ok()?;
+                Some(Ipv6Addr::from(octets).to_string())
+            },
+            Some((6, mac)) if mac.len() == 6 => Some(MacAddr::new(mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]).to_string()),
+            _ => None
+        }
+    }
+
+    fn capability_names(capabilities: u16) -> Vec<String> {
+        LLDP::CAPABILITY_NAMES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| capabilities & (1 << bit) != 0)
+            .map(|(_, name)| name.to_string())
+            .collect()
+    }
+}
+
+impl PacketAnalysis for LLDP {
+    fn short_description(&self) -> String {
+        format!(
+            "LLDP: chassis {} port {} ttl {}",
+            self.system_name.as_ref().unwrap_or(&self.chassis_id),
+            self.port_id,
+            self.ttl
+        )
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = Vec::new();
+        let mut management_addresses = self.management_addresses.iter();
+
+        for (tlv_type, offset, length) in &self.tlvs {
+            let (offset, length) = (*offset, *length);
+
+            let field = match *tlv_type {
+                LLDP::TLV_END => PacketField::new("lldp.end", "End of LLDPDU", FieldValue::None, offset, length),
+                LLDP::TLV_CHASSIS_ID => PacketField::new("lldp.chassis.id", "Chassis ID", self.chassis_id.as_str(), offset, length),
+                LLDP::TLV_PORT_ID => PacketField::new("lldp.port.id", "Port ID", self.port_id.as_str(), offset, length),
+                LLDP::TLV_TTL => PacketField::new("lldp.time_to_live", "Time to live", self.ttl, offset, length),
+                LLDP::TLV_PORT_DESCRIPTION => {
+                    PacketField::new("lldp.port.desc", "Port description", self.port_description.clone().unwrap_or_default(), offset, length)
+                },
+                LLDP::TLV_SYSTEM_NAME => {
+                    PacketField::new("lldp.tlv.system.name", "System name", self.system_name.clone().unwrap_or_default(), offset, length)
+                },
+                LLDP::TLV_SYSTEM_DESCRIPTION => PacketField::new(
+                    "lldp.tlv.system.desc",
+                    "System description",
+                    self.system_description.clone().unwrap_or_default(),
+                    offset,
+                    length
+                ),
+                LLDP::TLV_CAPABILITIES => PacketField::group("lldp.tlv.system_cap", "Capabilities", offset, length, vec![
+                    PacketField::new("lldp.tlv.system_cap.supported", "Supported", self.capabilities.join(", "), offset + 2, 2),
+                    PacketField::new("lldp.tlv.system_cap.enabled", "Enabled", self.enabled_capabilities.join(", "), offset + 4, 2),
+                ]),
+                LLDP::TLV_MANAGEMENT_ADDRESS => match management_addresses.next() {
+                    Some(address) => PacketField::new("lldp.mgn.addr", "Management address", address.as_str(), offset, length),
+                    None => continue
+                },
+                LLDP::TLV_ORGANIZATION_SPECIFIC if self.vlan_offset == Some(offset) => {
+                    PacketField::new("lldp.ieee.802_1.port_vlan.id", "Port VLAN ID", self.vlan.unwrap_or(0), offset, length)
+                },
+                tlv_type => PacketField::new("lldp.tlv.type", "TLV", tlv_type, offset, length).with_meaning(format!("{} bytes", length - 2))
+            };
+
+            fields.push(field);
+        }
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        LLDP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        self.chassis_id.clone()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
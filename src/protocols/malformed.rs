@@ -0,0 +1,159 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+#[derive(Clone, Copy)]
+pub enum MalformedReason {
+    TooShort {
+        required: usize,
+        available: usize,
+    },
+    BadHeaderLength(usize),
+    TotalLengthExceedsCapture {
+        declared: usize,
+        captured: usize,
+    },
+    SnaplenTruncation {
+        captured: usize,
+        original: usize,
+    },
+}
+
+pub struct Malformed {
+    layer: Protocol,
+    reason: MalformedReason,
+    offset: usize,
+    located: bool,
+    payload: FrameBuffer
+}
+
+impl Malformed {
+
+    const PROTOCOL: Protocol = Protocol::Malformed;
+
+    pub fn new(layer: Protocol, reason: MalformedReason, offset: usize, packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        Some(Box::new(Malformed {
+            layer,
+            reason,
+            offset: packet_data.offset() + offset,
+            located: false,
+            payload: packet_data.clone()
+        }))
+    }
+
+    pub fn truncated(layer: Protocol, captured: usize, original: usize) -> Box<dyn PacketAnalysis> {
+        Box::new(Malformed {
+            layer,
+            reason: MalformedReason::SnaplenTruncation { captured, original },
+            offset: captured,
+            located: true,
+            payload: FrameBuffer::default()
+        })
+    }
+
+    pub fn locate(&self, captured: usize, original: usize) -> Box<dyn PacketAnalysis> {
+        let reason = match self.reason {
+            MalformedReason::TooShort { .. } | MalformedReason::TotalLengthExceedsCapture { .. } if captured < original => {
+                MalformedReason::SnaplenTruncation { captured, original }
+            },
+            reason => reason
+        };
+
+        Box::new(Malformed {
+            layer: self.layer,
+            reason,
+            offset: self.offset,
+            located: true,
+            payload: self.payload.clone()
+        })
+    }
+
+    pub fn is_located(&self) -> bool {
+        self.located
+    }
+
+    pub fn layer(&self) -> Protocol {
+        self.layer
+    }
+
+    pub fn is_length_mismatch(&self) -> bool {
+        matches!(self.reason, MalformedReason::TotalLengthExceedsCapture { .. })
+    }
+
+    fn reason_name(&self) -> &'static str {
+        match self.reason {
+            MalformedReason::TooShort { .. } => "TOO_SHORT",
+            MalformedReason::BadHeaderLength(_) => "BAD_HEADER_LENGTH",
+            MalformedReason::TotalLengthExceedsCapture { .. } => "TOTAL_LENGTH_EXCEEDS_CAPTURE",
+            MalformedReason::SnaplenTruncation { .. } => "SNAPLEN_TRUNCATION",
+        }
+    }
+
+    fn reason_formatted(&self) -> String {
+        match self.reason {
+            MalformedReason::TooShort { required, available } => {
+                format!("too short ({} bytes, at least {} required)", available, required)
+            },
+            MalformedReason::BadHeaderLength(header_length) => format!("bad header length {}", header_length),
+            MalformedReason::TotalLengthExceedsCapture { declared, captured } => {
+                format!("total length {} exceeds captured {} bytes", declared, captured)
+            },
+            MalformedReason::SnaplenTruncation { captured, original } => {
+                format!("frame truncated by snaplen ({} of {} bytes captured)", captured, original)
+            }
+        }
+    }
+}
+
+impl PacketAnalysis for Malformed {
+    fn short_description(&self) -> String {
+        format!("MALFORMED {}: {} at offset {}", self.layer, self.reason_formatted(), self.offset)
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let offset = PacketField::generated("malformed.offset", "Offset", self.offset);
+
+        vec![
+            PacketField::generated("malformed.layer", "Layer", self.layer.to_string()),
+            PacketField::generated("malformed.reason", "Reason", self.reason_name()).with_meaning(self.reason_formatted()),
+            if self.located { offset } else { offset.with_meaning("within reassembled data") },
+            PacketField::generated("malformed.remaining", "Remaining bytes", self.payload.len()),
+        ]
+    }
+
+    fn protocol(&self) -> Protocol {
+        Malformed::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> Vec<String> {
+        vec![String::from(self.reason_name())]
+    }
+}
@@ -3,4 +3,25 @@ pub(crate) mod ipv4;
 pub(crate) mod ipv6;
 pub(crate) mod tcp;
 pub(crate) mod udp;
-pub(crate) mod arp;
\ No newline at end of file
+pub(crate) mod arp;
+pub(crate) mod http;
+pub(crate) mod tls;
+pub(crate) mod quic;
+pub(crate) mod ipv6_extension;
+pub(crate) mod reassembled;
+pub(crate) mod gre;
+pub(crate) mod erspan;
+pub(crate) mod vxlan;
+pub(crate) mod geneve;
+pub(crate) mod mpls;
+pub(crate) mod llc;
+pub(crate) mod lldp;
+pub(crate) mod cdp;
+pub(crate) mod stp;
+pub(crate) mod ntp;
+pub(crate) mod igmp;
+pub(crate) mod sctp;
+pub(crate) mod malformed;
+pub(crate) mod data;
+pub(crate) mod icmp;
+pub(crate) mod dns;
\ No newline at end of file
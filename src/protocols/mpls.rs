@@ -0,0 +1,154 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::ethernet::Ethernet;
+use crate::protocols::ipv4::IPv4;
+use crate::protocols::ipv6::IPv6;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct MplsLabel {
+    pub label: u32,
+    pub traffic_class: u8,
+    pub bottom_of_stack: bool,
+    pub ttl: u8,
+}
+
+pub struct MPLS {
+    multicast: bool,
+    labels: Vec<MplsLabel>,
+    payload: FrameBuffer
+}
+
+impl MPLS {
+
+    const PROTOCOL: Protocol = Protocol::MPLS;
+    const LABEL_LENGTH: usize = 4;
+    const CONTROL_WORD_LENGTH: usize = 4;
+
+    const IPV4_EXPLICIT_NULL: u32 = 0;
+    const IPV6_EXPLICIT_NULL: u32 = 2;
+
+    pub fn new(multicast: bool, packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let mut labels = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let entry = packet_data.get(offset..offset + MPLS::LABEL_LENGTH)?;
+            let value = u32::from_be_bytes([entry[0], entry[1], entry[2], entry[3]]);
+            let bottom_of_stack = value & 0x100 != 0;
+
+            labels.push(MplsLabel {
+                label: value >> 12,
+                traffic_class: ((value >> 9) & 0x07) as u8,
+                bottom_of_stack,
+                ttl: (value & 0xFF) as u8,
+            });
+
+            offset += MPLS::LABEL_LENGTH;
+
+            if bottom_of_stack {
+                break;
+            }
+        }
+
+        Some(Box::new(MPLS {
+            multicast,
+            labels,
+            payload: packet_data.slice(offset..)
+        }))
+    }
+
+    pub fn labels(&self) -> &Vec<MplsLabel> {
+        &self.labels
+    }
+
+    fn label_name(label: u32) -> &'static str {
+        match label {
+            0 => "IPv4 explicit null",
+            1 => "Router alert",
+            2 => "IPv6 explicit null",
+            3 => "Implicit null",
+            7 => "Entropy label indicator",
+            13 => "Generic associated channel",
+            14 => "OAM alert",
+            15 => "Extension",
+            _ => ""
+        }
+    }
+}
+
+impl PacketAnalysis for MPLS {
+    fn short_description(&self) -> String {
+        let labels: Vec<String> = self.labels.iter().map(|label| label.label.to_string()).collect();
+
+        format!("MPLS: labels [{}] len {}", labels.join(", "), self.payload.len())
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        match self.labels.last().map(|label| label.label) {
+            Some(MPLS::IPV4_EXPLICIT_NULL) => return IPv4::new(&self.payload),
+            Some(MPLS::IPV6_EXPLICIT_NULL) => return IPv6::new(&self.payload),
+            _ => {}
+        }
+
+        let first_byte = *self.payload.first()?;
+
+        match first_byte >> 4 {
+            4 if first_byte & 0x0F >= 5 => IPv4::new(&self.payload),
+            6 => IPv6::new(&self.payload),
+            0 => Ethernet::new(&self.payload.checked_slice(MPLS::CONTROL_WORD_LENGTH..)?),
+            _ => Ethernet::new(&self.payload)
+        }
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![PacketField::generated("mpls.multicast", "Multicast", self.multicast)];
+
+        for (idx, label) in self.labels.iter().enumerate() {
+            let offset = idx * MPLS::LABEL_LENGTH;
+            let mut field = PacketField::new("mpls.label", "Label", label.label, offset, 3).with_children(vec![
+                PacketField::new("mpls.exp", "Traffic class", label.traffic_class, offset + 2, 1),
+                PacketField::new("mpls.bottom", "Bottom of stack", label.bottom_of_stack, offset + 2, 1),
+                PacketField::new("mpls.ttl", "TTL", label.ttl, offset + 3, 1),
+            ]);
+
+            let name = MPLS::label_name(label.label);
+
+            if !name.is_empty() {
+                field = field.with_meaning(name);
+            }
+            fields.push(field);
+        }
+
+        fields.push(PacketField::generated("mpls.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        MPLS::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,198 @@
+use std::any::Any;
+use std::net::Ipv4Addr;
+
+use chrono::{DateTime, Utc};
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct NTP {
+    leap_indicator: u8,
+    version: u8,
+    mode: u8,
+    stratum: u8,
+    poll: i8,
+    precision: i8,
+    root_delay: u32,
+    root_dispersion: u32,
+    reference_id: [u8; 4],
+    reference_timestamp: u64,
+    origin_timestamp: u64,
+    receive_timestamp: u64,
+    transmit_timestamp: u64,
+    payload: FrameBuffer
+}
+
+impl NTP {
+
+    const PROTOCOL: Protocol = Protocol::NTP;
+    const HEADER_LENGTH: usize = 48;
+    const UNIX_EPOCH_OFFSET: u64 = 2_208_988_800;
+
+    pub const MODE_CLIENT: u8 = 3;
+    pub const MODE_SERVER: u8 = 4;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..NTP::HEADER_LENGTH)?;
+        let version = (header[0] >> 3) & 0x07;
+
+        if version == 0 || version > 4 {
+            return None;
+        }
+
+        Some(Box::new(NTP {
+            leap_indicator: header[0] >> 6,
+            version,
+            mode: header[0] & 0x07,
+            stratum: header[1],
+            poll: header[2] as i8,
+            precision: header[3] as i8,
+            root_delay: read_u32(header, 4),
+            root_dispersion: read_u32(header, 8),
+            reference_id: [header[12], header[13], header[14], header[15]],
+            reference_timestamp: read_u64(header, 16),
+            origin_timestamp: read_u64(header, 24),
+            receive_timestamp: read_u64(header, 32),
+            transmit_timestamp: read_u64(header, 40),
+            payload: packet_data.slice(NTP::HEADER_LENGTH..)
+        }))
+    }
+
+    pub fn mode(&self) -> u8 {
+        self.mode
+    }
+
+    pub fn stratum(&self) -> u8 {
+        self.stratum
+    }
+
+    pub fn origin_timestamp(&self) -> u64 {
+        self.origin_timestamp
+    }
+
+    pub fn receive_timestamp(&self) -> u64 {
+        self.receive_timestamp
+    }
+
+    pub fn transmit_timestamp(&self) -> u64 {
+        self.transmit_timestamp
+    }
+
+    pub fn timestamp_to_seconds(timestamp: u64) -> f64 {
+        (timestamp >> 32) as f64 + (timestamp & 0xFFFF_FFFF) as f64 / 4_294_967_296.0
+    }
+
+    pub fn datetime_to_seconds<Tz: chrono::TimeZone>(datetime: &DateTime<Tz>) -> f64 {
+        (datetime.timestamp() as u64 + NTP::UNIX_EPOCH_OFFSET) as f64 + datetime.timestamp_subsec_nanos() as f64 / 1e9
+    }
+
+    fn format_timestamp(timestamp: u64) -> String {
+        if timestamp == 0 {
+            return String::from("(not set)");
+        }
+
+        let seconds = (timestamp >> 32) as i64 - NTP::UNIX_EPOCH_OFFSET as i64;
+        let nanoseconds = (((timestamp & 0xFFFF_FFFF) * 1_000_000_000) >> 32) as u32;
+
+        match DateTime::<Utc>::from_timestamp(seconds, nanoseconds) {
+            Some(datetime) => datetime.format("%Y-%m-%d %H:%M:%S%.6f UTC").to_string(),
+            None => format!("{}", timestamp)
+        }
+    }
+
+    fn format_short(value: u32) -> String {
+        format!("{:.6}s", (value >> 16) as f64 + (value & 0xFFFF) as f64 / 65536.0)
+    }
+
+    fn format_reference_id(&self) -> String {
+        match self.stratum {
+            0 | 1 => String::from_utf8_lossy(&self.reference_id).trim_end_matches('\0').to_string(),
+            _ => Ipv4Addr::from(self.reference_id).to_string()
+        }
+    }
+
+    fn mode_name(&self) -> &'static str {
+        match self.mode {
+            1 => "symmetric active",
+            2 => "symmetric passive",
+            NTP::MODE_CLIENT => "client",
+            NTP::MODE_SERVER => "server",
+            5 => "broadcast",
+            6 => "control",
+            7 => "private",
+            _ => "reserved"
+        }
+    }
+
+    fn leap_indicator_name(&self) -> &'static str {
+        match self.leap_indicator {
+            0 => "no warning",
+            1 => "last minute has 61 seconds",
+            2 => "last minute has 59 seconds",
+            _ => "clock unsynchronized"
+        }
+    }
+}
+
+impl PacketAnalysis for NTP {
+    fn short_description(&self) -> String {
+        format!("NTPv{}: {} stratum {}", self.version, self.mode_name(), self.stratum)
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        vec![
+            PacketField::new("ntp.flags.li", "Leap indicator", self.leap_indicator, 0, 1).with_meaning(self.leap_indicator_name()),
+            PacketField::new("ntp.flags.vn", "Version", self.version, 0, 1),
+            PacketField::new("ntp.flags.mode", "Mode", self.mode, 0, 1).with_meaning(self.mode_name()),
+            PacketField::new("ntp.stratum", "Stratum", self.stratum, 1, 1),
+            PacketField::new("ntp.ppoll", "Poll", self.poll as f64, 2, 1).with_meaning(format!("{}s", 2f64.powi(self.poll as i32))),
+            PacketField::new("ntp.precision", "Precision", self.precision as f64, 3, 1)
+                .with_meaning(format!("{:.9}s", 2f64.powi(self.precision as i32))),
+            PacketField::new("ntp.rootdelay", "Root delay", NTP::format_short(self.root_delay), 4, 4),
+            PacketField::new("ntp.rootdispersion", "Root dispersion", NTP::format_short(self.root_dispersion), 8, 4),
+            PacketField::new("ntp.refid", "Reference ID", self.format_reference_id(), 12, 4),
+            PacketField::new("ntp.reftime", "Reference timestamp", NTP::format_timestamp(self.reference_timestamp), 16, 8),
+            PacketField::new("ntp.org", "Origin timestamp", NTP::format_timestamp(self.origin_timestamp), 24, 8),
+            PacketField::new("ntp.rec", "Receive timestamp", NTP::format_timestamp(self.receive_timestamp), 32, 8),
+            PacketField::new("ntp.xmt", "Transmit timestamp", NTP::format_timestamp(self.transmit_timestamp), 40, 8),
+        ]
+    }
+
+    fn protocol(&self) -> Protocol {
+        NTP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    ((read_u32(data, offset) as u64) << 32) | read_u32(data, offset + 4) as u64
+}
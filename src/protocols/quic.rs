@@ -0,0 +1,691 @@
+use std::any::Any;
+
+use aes::Aes128;
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::cipher::generic_array::GenericArray;
+use aes_gcm::Aes128Gcm;
+use aes_gcm::aead::{Aead, Payload};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::tls::TlsHandshake;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+const VERSION_NEGOTIATION: u32 = 0x00000000;
+const VERSION_1: u32 = 0x00000001;
+const VERSION_2: u32 = 0x6b3343cf;
+
+const INITIAL_SALT_V1: [u8; 20] = [
+    0x38, 0x76, 0x2c, 0xf7, 0xf5, 0x59, 0x34, 0xb3, 0x4d, 0x17,
+    0x9a, 0xe6, 0xa4, 0xc8, 0x0c, 0xad, 0xcc, 0xbb, 0x7f, 0x0a,
+];
+const INITIAL_SALT_V2: [u8; 20] = [
+    0x0d, 0xed, 0xe3, 0xde, 0xf7, 0x00, 0xa6, 0xdb, 0x81, 0x93,
+    0x81, 0xbe, 0x6e, 0x26, 0x9d, 0xcb, 0xf9, 0xbd, 0x2e, 0xd9,
+];
+
+const HEADER_FORM_LONG: u8 = 0x80;
+const FIXED_BIT: u8 = 0x40;
+const SAMPLE_LENGTH: usize = 16;
+const TAG_LENGTH: usize = 16;
+
+const FRAME_PADDING: u64 = 0x00;
+const FRAME_PING: u64 = 0x01;
+const FRAME_ACK: u64 = 0x02;
+const FRAME_ACK_ECN: u64 = 0x03;
+const FRAME_CRYPTO: u64 = 0x06;
+const FRAME_CONNECTION_CLOSE: u64 = 0x1c;
+const FRAME_CONNECTION_CLOSE_APPLICATION: u64 = 0x1d;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum QuicPacketType {
+    Initial,
+    ZeroRtt,
+    Handshake,
+    Retry,
+    VersionNegotiation,
+    Short,
+}
+
+struct QuicPacket {
+    packet_type: QuicPacketType,
+    version: u32,
+    destination_connection_id: Vec<u8>,
+    source_connection_id: Vec<u8>,
+    token_length: u64,
+    token_length_range: (usize, usize),
+    length: u64,
+    length_range: (usize, usize),
+    packet_number: Option<u64>,
+    packet_number_range: (usize, usize),
+    frames: Vec<String>,
+}
+
+struct InitialKeys {
+    key: Vec<u8>,
+    iv: Vec<u8>,
+    header_protection: Vec<u8>,
+}
+
+pub struct QUIC {
+    packets: Vec<QuicPacket>,
+    packet_ranges: Vec<(usize, usize)>,
+    handshake: TlsHandshake,
+    payload: FrameBuffer
+}
+
+impl QUIC {
+
+    const PROTOCOL: Protocol = Protocol::QUIC;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let first_byte = *packet_data.first()?;
+
+        if first_byte & HEADER_FORM_LONG == 0 {
+            if first_byte & FIXED_BIT == 0 {
+                return None;
+            }
+
+            return Some(Box::new(QUIC {
+                packets: vec![QuicPacket::short()],
+                packet_ranges: vec![(0, 1)],
+                handshake: TlsHandshake::default(),
+                payload: packet_data.slice(1..)
+            }));
+        }
+
+        let mut packets = Vec::new();
+        let mut packet_ranges = Vec::new();
+        let mut crypto_fragments = Vec::new();
+        let mut offset = 0;
+
+        while offset < packet_data.len() && packet_data[offset] & HEADER_FORM_LONG != 0 {
+            match QUIC::parse_long_packet(&packet_data[offset..], &mut crypto_fragments) {
+                Some((packet, packet_length)) => {
+                    packets.push(packet);
+                    packet_ranges.push((offset, packet_length));
+                    offset += packet_length;
+                },
+                None => break
+            }
+        }
+
+        if packets.is_empty() {
+            return None;
+        }
+
+        Some(Box::new(QUIC {
+            packets,
+            packet_ranges,
+            handshake: TlsHandshake::parse(&QUIC::assemble_crypto_stream(crypto_fragments)),
+            payload: packet_data.slice(offset..)
+        }))
+    }
+
+    fn parse_long_packet(data: &[u8], crypto_fragments: &mut Vec<(u64, Vec<u8>)>) -> Option<(QuicPacket, usize)> {
+        let first_byte = data[0];
+        let version = read_u32(data, 1)?;
+
+        let destination_length = *data.get(5)? as usize;
+        let destination_connection_id = data.get(6..6 + destination_length)?.to_vec();
+        let mut offset = 6 + destination_length;
+
+        let source_length = *data.get(offset)? as usize;
+        let source_connection_id = data.get(offset + 1..offset + 1 + source_length)?.to_vec();
+        offset += 1 + source_length;
+
+        let mut packet = QuicPacket {
+            packet_type: QuicPacketType::VersionNegotiation,
+            version,
+            destination_connection_id,
+            source_connection_id,
+            token_length: 0,
+            token_length_range: (0, 0),
+            length: 0,
+            length_range: (0, 0),
+            packet_number: None,
+            packet_number_range: (0, 0),
+            frames: Vec::new(),
+        };
+
+        if version == VERSION_NEGOTIATION {
+            return Some((packet, data.len()));
+        }
+
+        if first_byte & FIXED_BIT == 0 {
+            return None;
+        }
+
+        packet.packet_type = QUIC::long_packet_type(version, (first_byte >> 4) & 0x03)?;
+
+        if packet.packet_type == QuicPacketType::Retry {
+            return Some((packet, data.len()));
+        }
+
+        if packet.packet_type == QuicPacketType::Initial {
+            let (token_length, read) = read_varint(data, offset)?;
+            packet.token_length = token_length;
+            packet.token_length_range = (offset, read);
+            offset += read + token_length as usize;
+        }
+
+        let (length, read) = read_varint(data, offset)?;
+        packet.length = length;
+        packet.length_range = (offset, read);
+        offset += read;
+
+        let packet_end = (offset + length as usize).min(data.len());
+
+        if packet.packet_type == QuicPacketType::Initial {
+            let plaintext = QUIC::decrypt_initial(&data[..packet_end], offset, &packet);
+
+            if let Some((packet_number, packet_number_length, plaintext)) = plaintext {
+                packet.packet_number = Some(packet_number);
+                packet.packet_number_range = (offset, packet_number_length);
+                packet.frames = QUIC::parse_frames(&plaintext, crypto_fragments);
+            }
+        }
+
+        Some((packet, packet_end))
+    }
+
+    fn long_packet_type(version: u32, type_bits: u8) -> Option<QuicPacketType> {
+        let packet_type = match (version, type_bits) {
+            (VERSION_2, 0) => QuicPacketType::Retry,
+            (VERSION_2, 1) => QuicPacketType::Initial,
+            (VERSION_2, 2) => QuicPacketType::ZeroRtt,
+            (VERSION_2, 3) => QuicPacketType::Handshake,
+            (_, 0) => QuicPacketType::Initial,
+            (_, 1) => QuicPacketType::ZeroRtt,
+            (_, 2) => QuicPacketType::Handshake,
+            (_, 3) => QuicPacketType::Retry,
+            _ => return None
+        };
+
+        Some(packet_type)
+    }
+
+    fn decrypt_initial(data: &[u8], packet_number_offset: usize, packet: &QuicPacket) -> Option<(u64, usize, Vec<u8>)> {
+        ["client in", "server in"]
+            .iter()
+            .filter_map(|label| InitialKeys::derive(packet.version, &packet.destination_connection_id, label))
+            .find_map(|keys| keys.decrypt(data, packet_number_offset))
+    }
+
+    fn parse_frames(plaintext: &[u8], crypto_fragments: &mut Vec<(u64, Vec<u8>)>) -> Vec<String> {
+        let mut frames = Vec::new();
+        let mut offset = 0;
+        let mut padding = 0;
+
+        while offset < plaintext.len() {
+            let (frame_type, read) = match read_varint(plaintext, offset) {
+                Some(value) => value,
+                None => break
+            };
+            offset += read;
+
+            if frame_type == FRAME_PADDING {
+                padding += 1;
+                continue;
+            }
+
+            let parsed = match frame_type {
+                FRAME_PING => Some((String::from("PING"), offset)),
+                FRAME_ACK | FRAME_ACK_ECN => QUIC::parse_ack_frame(plaintext, offset, frame_type == FRAME_ACK_ECN),
+                FRAME_CRYPTO => QUIC::parse_crypto_frame(plaintext, offset, crypto_fragments),
+                FRAME_CONNECTION_CLOSE | FRAME_CONNECTION_CLOSE_APPLICATION => {
+                    QUIC::parse_connection_close_frame(plaintext, offset, frame_type == FRAME_CONNECTION_CLOSE)
+                },
+                _ => None
+            };
+
+            match parsed {
+                Some((description, next)) => {
+                    frames.push(description);
+                    offset = next;
+                },
+                None => {
+                    frames.push(format!("Unknown frame 0x{:02x}", frame_type));
+                    break;
+                }
+            }
+        }
+
+        if padding > 0 {
+            frames.push(format!("PADDING len {}", padding));
+        }
+
+        frames
+    }
+
+    fn parse_ack_frame(data: &[u8], offset: usize, ecn: bool) -> Option<(String, usize)> {
+        let (largest_acknowledged, read) = read_varint(data, offset)?;
+        let mut offset = offset + read;
+        let (_, read) = read_varint(data, offset)?;
+        offset += read;
+        let (range_count, read) = read_varint(data, offset)?;
+        offset += read;
+        let (_, read) = read_varint(data, offset)?;
+        offset += read;
+
+        let extra_fields = range_count * 2 + if ecn { 3 } else { 0 };
+
+        for _ in 0..extra_fields {
+            let (_, read) = read_varint(data, offset)?;
+            offset += read;
+        }
+
+        Some((format!("ACK largest {}", largest_acknowledged), offset))
+    }
+
+    fn parse_crypto_frame(data: &[u8], offset: usize, crypto_fragments: &mut Vec<(u64, Vec<u8>)>) -> Option<(String, usize)> {
+        let (crypto_offset, read) = read_varint(data, offset)?;
+        let offset = offset + read;
+        let (length, read) = read_varint(data, offset)?;
+        let offset = offset + read;
+        let crypto_data = data.get(offset..offset + length as usize)?;
+
+        crypto_fragments.push((crypto_offset, crypto_data.to_vec()));
+
+        Some((format!("CRYPTO offset {} len {}", crypto_offset, length), offset + length as usize))
+    }
+
+    fn parse_connection_close_frame(data: &[u8], offset: usize, transport: bool) -> Option<(String, usize)> {
+        let (error_code, read) = read_varint(data, offset)?;
+        let mut offset = offset + read;
+
+        if transport {
+            let (_, read) = read_varint(data, offset)?;
+            offset += read;
+        }
+
+        let (reason_length, read) = read_varint(data, offset)?;
+        offset += read;
+        let reason = data.get(offset..offset + reason_length as usize)?;
+
+        Some((
+            format!("CONNECTION_CLOSE error 0x{:x} {}", error_code, String::from_utf8_lossy(reason)),
+            offset + reason_length as usize
+        ))
+    }
+
+    fn assemble_crypto_stream(mut crypto_fragments: Vec<(u64, Vec<u8>)>) -> Vec<u8> {
+        crypto_fragments.sort_by_key(|(offset, _)| *offset);
+
+        let mut stream: Vec<u8> = Vec::new();
+
+        for (offset, data) in crypto_fragments {
+            let offset = offset as usize;
+
+            if offset > stream.len() {
+                break;
+            }
+
+            if offset + data.len() > stream.len() {
+                stream.extend_from_slice(&data[stream.len() - offset..]);
+            }
+        }
+
+        stream
+    }
+
+    fn connection_id_to_string(connection_id: &[u8]) -> String {
+        connection_id.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+impl QuicPacket {
+    fn fields(&self, offset: usize, length: usize) -> PacketField {
+        let mut children = Vec::new();
+
+        if self.packet_type != QuicPacketType::Short {
+            let destination_length = self.destination_connection_id.len();
+            let source_offset = offset + 6 + destination_length;
+
+            children.push(PacketField::hex("quic.version", "Version", self.version, offset + 1, 4));
+            children.push(PacketField::new(
+                "quic.dcid",
+                "Destination connection ID",
+                self.destination_connection_id.as_slice(),
+                offset + 6,
+                destination_length
+            ));
+            children.push(PacketField::new(
+                "quic.scid",
+                "Source connection ID",
+                self.source_connection_id.as_slice(),
+                source_offset + 1,
+                self.source_connection_id.len()
+            ));
+
+            if self.packet_type == QuicPacketType::Initial {
+                let (token_length_offset, token_length_length) = self.token_length_range;
+                children.push(PacketField::new("quic.token_length", "Token length", self.token_length, offset + token_length_offset, token_length_length));
+            }
+
+            let (length_offset, length_length) = self.length_range;
+            children.push(PacketField::new("quic.length", "Length", self.length, offset + length_offset, length_length));
+
+            match self.packet_number {
+                Some(packet_number) => {
+                    let (packet_number_offset, packet_number_length) = self.packet_number_range;
+                    children.push(PacketField::new(
+                        "quic.packet_number",
+                        "Packet number",
+                        packet_number,
+                        offset + packet_number_offset,
+                        packet_number_length
+                    ));
+                    children.push(PacketField::generated("quic.frames", "Frames", self.frames.len()).with_children(
+                        self.frames
+                            .iter()
+                            .map(|frame| PacketField::generated("quic.frame", "Frame", frame.as_str()))
+                            .collect()
+                    ));
+                },
+                None => children.push(PacketField::generated("quic.remaining_payload", "Payload", "encrypted"))
+            }
+        }
+
+        PacketField::group("quic.packet", "Packet", offset, length, children).with_meaning(format!("{:?}", self.packet_type))
+    }
+
+    fn short() -> QuicPacket {
+        QuicPacket {
+            packet_type: QuicPacketType::Short,
+            version: 0,
+            destination_connection_id: Vec::new(),
+            source_connection_id: Vec::new(),
+            token_length: 0,
+            token_length_range: (0, 0),
+            length: 0,
+            length_range: (0, 0),
+            packet_number: None,
+            packet_number_range: (0, 0),
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl InitialKeys {
+    fn derive(version: u32, destination_connection_id: &[u8], label: &str) -> Option<InitialKeys> {
+        let (salt, prefix) = match version {
+            VERSION_1 => (INITIAL_SALT_V1, "quic"),
+            VERSION_2 => (INITIAL_SALT_V2, "quicv2"),
+            _ => return None
+        };
+
+        let initial = Hkdf::<Sha256>::new(Some(&salt), destination_connection_id);
+        let mut secret = [0u8; 32];
+        initial.expand(&hkdf_label(label, 32), &mut secret).ok()?;
+
+        let secret = Hkdf::<Sha256>::from_prk(&secret).ok()?;
+        let mut key = vec![0u8; 16];
+        let mut iv = vec![0u8; 12];
+        let mut header_protection = vec![0u8; 16];
+
+        secret.expand(&hkdf_label(&format!("{} key", prefix), 16), &mut key).ok()?;
+        secret.expand(&hkdf_label(&format!("{} iv", prefix), 12), &mut iv).ok()?;
+        secret.expand(&hkdf_label(&format!("{} hp", prefix), 16), &mut header_protection).ok()?;
+
+        Some(InitialKeys { key, iv, header_protection })
+    }
+
+    fn decrypt(&self, data: &[u8], packet_number_offset: usize) -> Option<(u64, usize, Vec<u8>)> {
+        let sample = data.get(packet_number_offset + 4..packet_number_offset + 4 + SAMPLE_LENGTH)?;
+
+        let header_cipher = Aes128::new(GenericArray::from_slice(&self.header_protection));
+        let mut mask = GenericArray::clone_from_slice(sample);
+        header_cipher.encrypt_block(&mut mask);
+
+        let mut header = data[..packet_number_offset].to_vec();
+        header[0] ^= mask[0] & 0x0f;
+        let packet_number_length = (header[0] & 0x03) as usize + 1;
+
+        let mut packet_number: u64 = 0;
+        for idx in 0..packet_number_length {
+            let byte = data.get(packet_number_offset + idx)? ^ mask[1 + idx];
+            header.push(byte);
+            packet_number = (packet_number << 8) | byte as u64;
+        }
+
+        let ciphertext = &data[packet_number_offset + packet_number_length..];
+        if ciphertext.len() < TAG_LENGTH {
+            return None;
+        }
+
+        let mut nonce = self.iv.clone();
+        for (idx, byte) in packet_number.to_be_bytes().iter().enumerate() {
+            nonce[idx + 4] ^= byte;
+        }
+
+        let cipher = Aes128Gcm::new(GenericArray::from_slice(&self.key));
+        let plaintext = cipher.decrypt(
+            GenericArray::from_slice(&nonce),
+            Payload { msg: ciphertext, aad: &header }
+        ).ok()?;
+
+        Some((packet_number, packet_number_length, plaintext))
+    }
+}
+
+impl PacketAnalysis for QUIC {
+    fn short_description(&self) -> String {
+        let packet_types: Vec<String> = self.packets
+            .iter()
+            .map(|packet| format!("{:?}", packet.packet_type))
+            .collect();
+
+        let mut description = format!("QUIC: {}", packet_types.join(", "));
+
+        if let Some(packet) = self.packets.first() {
+            if packet.packet_type != QuicPacketType::Short {
+                description.push_str(&format!(" DCID {}", QUIC::connection_id_to_string(&packet.destination_connection_id)));
+            }
+        }
+
+        if let Some(server_name) = self.handshake.server_name() {
+            description.push_str(&format!(" SNI {}", server_name));
+        }
+
+        description
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields: Vec<PacketField> = self.packets
+            .iter()
+            .zip(self.packet_ranges.iter())
+            .map(|(packet, (offset, length))| packet.fields(*offset, *length))
+            .collect();
+
+        // The handshake is parsed from decrypted CRYPTO frames, so its offsets are relative to
+        // the reassembled CRYPTO stream rather than to the protected bytes of this layer
+        if !self.handshake.is_empty() {
+            fields.extend(self.handshake.fields().iter().map(PacketField::as_relative));
+        }
+        fields.push(PacketField::generated("quic.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        QUIC::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        self.handshake.server_name().cloned().unwrap_or_default()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn hkdf_label(label: &str, length: u16) -> Vec<u8> {
+    let full_label = format!("tls13 {}", label);
+    let mut info = Vec::with_capacity(4 + full_label.len());
+
+    info.extend_from_slice(&length.to_be_bytes());
+    info.push(full_label.len() as u8);
+    info.extend_from_slice(full_label.as_bytes());
+    info.push(0);
+
+    info
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_varint(data: &[u8], offset: usize) -> Option<(u64, usize)> {
+    let first_byte = *data.get(offset)?;
+    let length = 1usize << (first_byte >> 6);
+    let bytes = data.get(offset..offset + length)?;
+
+    let value = bytes[1..]
+        .iter()
+        .fold((first_byte & 0x3f) as u64, |value, byte| (value << 8) | *byte as u64);
+
+    Some((value, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use aes_gcm::aead::AeadInPlace;
+
+    use super::*;
+
+    // RFC 9001 Appendix A
+    const DESTINATION_CONNECTION_ID: &str = "8394c8f03e515708";
+    const CLIENT_HELLO_CRYPTO_FRAME: &str = concat!(
+        "060040f1010000ed0303ebf8fa56f12939b9584a3896472ec40bb863cfd3e868",
+        "04fe3a47f06a2b69484c00000413011302010000c000000010000e00000b6578",
+        "616d706c652e636f6dff01000100000a00080006001d00170018001000070005",
+        "04616c706e000500050100000000003300260024001d00209370b2c9caa47fba",
+        "baf4559fedba753de171fa71f50f1ce15d43e994ec74d748002b000302030400",
+        "0d0010000e0403050306030203080408050806002d00020101001c0002400100",
+        "3900320408ffffffffffffffff05048000ffff07048000ffff08011001048000",
+        "75300901100f088394c8f03e51570806048000ffff"
+    );
+    const UNPROTECTED_HEADER: &str = "c300000001088394c8f03e5157080000449e00000002";
+    const PROTECTED_HEADER: &str = "c000000001088394c8f03e5157080000449e7b9aec34";
+    const SAMPLE: &str = "d1b1c98dd7689fb8ec11d242b123dc9b";
+    const PAYLOAD_LENGTH: usize = 1162;
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len()).step_by(2).map(|idx| u8::from_str_radix(&value[idx..idx + 2], 16).unwrap()).collect()
+    }
+
+    fn header_mask(keys: &InitialKeys, sample: &[u8]) -> Vec<u8> {
+        let mut mask = GenericArray::clone_from_slice(sample);
+        Aes128::new(GenericArray::from_slice(&keys.header_protection)).encrypt_block(&mut mask);
+        mask.to_vec()
+    }
+
+    fn client_initial() -> Vec<u8> {
+        let keys = InitialKeys::derive(VERSION_1, &hex(DESTINATION_CONNECTION_ID), "client in").unwrap();
+        let header = hex(UNPROTECTED_HEADER);
+
+        let mut payload = hex(CLIENT_HELLO_CRYPTO_FRAME);
+        payload.resize(PAYLOAD_LENGTH, 0);
+
+        let mut nonce = keys.iv.clone();
+        nonce[11] ^= 2;
+        Aes128Gcm::new(GenericArray::from_slice(&keys.key))
+            .encrypt_in_place(GenericArray::from_slice(&nonce), &header, &mut payload)
+            .unwrap();
+
+        let mut packet = header;
+        let packet_number_offset = packet.len() - 4;
+        packet.extend_from_slice(&payload);
+
+        let mask = header_mask(&keys, &packet[packet_number_offset + 4..packet_number_offset + 4 + SAMPLE_LENGTH]);
+        packet[0] ^= mask[0] & 0x0f;
+        for idx in 0..4 {
+            packet[packet_number_offset + idx] ^= mask[1 + idx];
+        }
+
+        packet
+    }
+
+    #[test]
+    fn derives_client_initial_keys() {
+        let keys = InitialKeys::derive(VERSION_1, &hex(DESTINATION_CONNECTION_ID), "client in").unwrap();
+
+        assert_eq!(keys.key, hex("1f369613dd76d5467730efcbe3b1a22d"));
+        assert_eq!(keys.iv, hex("fa044b2f42a3fd3b46fb255c"));
+        assert_eq!(keys.header_protection, hex("9f50449e04a0e810283a1e9933adedd2"));
+    }
+
+    #[test]
+    fn derives_server_initial_keys() {
+        let keys = InitialKeys::derive(VERSION_1, &hex(DESTINATION_CONNECTION_ID), "server in").unwrap();
+
+        assert_eq!(keys.key, hex("cf3a5331653c364c88f0f379b6067e37"));
+        assert_eq!(keys.iv, hex("0ac1493ca1905853b0bba03e"));
+        assert_eq!(keys.header_protection, hex("c206b8d9b9f0f37644430b490eeaa314"));
+    }
+
+    #[test]
+    fn protects_client_initial_header() {
+        let keys = InitialKeys::derive(VERSION_1, &hex(DESTINATION_CONNECTION_ID), "client in").unwrap();
+        let packet = client_initial();
+
+        assert_eq!(packet.len(), 1200);
+        assert_eq!(&packet[22..22 + SAMPLE_LENGTH], hex(SAMPLE).as_slice());
+        assert_eq!(header_mask(&keys, &hex(SAMPLE))[..5], hex("437b9aec36")[..]);
+        assert_eq!(&packet[..22], hex(PROTECTED_HEADER).as_slice());
+    }
+
+    #[test]
+    fn decrypts_client_initial() {
+        let packet = client_initial();
+        let keys = InitialKeys::derive(VERSION_1, &hex(DESTINATION_CONNECTION_ID), "client in").unwrap();
+        let (packet_number, packet_number_length, plaintext) = keys.decrypt(&packet, 18).unwrap();
+
+        assert_eq!(packet_number, 2);
+        assert_eq!(packet_number_length, 4);
+        assert_eq!(plaintext.len(), PAYLOAD_LENGTH);
+        assert_eq!(&plaintext[..4], hex("060040f1").as_slice());
+
+        let analysis = QUIC::new(&FrameBuffer::from(packet)).unwrap();
+        let quic = analysis.as_any().downcast_ref::<QUIC>().unwrap();
+
+        assert_eq!(quic.packets[0].packet_type, QuicPacketType::Initial);
+        assert_eq!(quic.packets[0].packet_number, Some(2));
+        assert_eq!(quic.handshake.server_name().map(String::as_str), Some("example.com"));
+        assert_eq!(analysis.destination(), "example.com");
+    }
+
+    #[test]
+    fn locates_initial_header_fields() {
+        let analysis = QUIC::new(&FrameBuffer::from(client_initial())).unwrap();
+        let fields = analysis.fields();
+        let locate = |name: &str| fields[0].find(name).first().map(|field| (field.offset, field.length));
+
+        assert_eq!(locate("quic.token_length"), Some((15, 1)));
+        assert_eq!(locate("quic.length"), Some((16, 2)));
+        assert_eq!(locate("quic.packet_number"), Some((18, 4)));
+    }
+}
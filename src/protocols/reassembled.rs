@@ -0,0 +1,105 @@
+use std::any::Any;
+
+use pnet::packet::ip::IpNextHeaderProtocol;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::ipv4::IPv4;
+use crate::protocols::ipv6::IPv6;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+#[derive(Clone)]
+pub struct Reassembled {
+    ip_version: Protocol,
+    next_protocol: IpNextHeaderProtocol,
+    frames: Vec<usize>,
+    overlapping: bool,
+    payload: FrameBuffer
+}
+
+impl Reassembled {
+
+    const PROTOCOL: Protocol = Protocol::Reassembled;
+
+    pub fn new(
+        ip_version: Protocol,
+        next_protocol: IpNextHeaderProtocol,
+        frames: Vec<usize>,
+        overlapping: bool,
+        payload: FrameBuffer
+    ) -> Box<dyn PacketAnalysis> {
+        Box::new(Reassembled {
+            ip_version,
+            next_protocol,
+            frames,
+            overlapping,
+            payload
+        })
+    }
+
+    fn frames_formatted(&self) -> String {
+        let frames: Vec<String> = self.frames
+            .iter()
+            .map(|frame| format!("#{}", frame))
+            .collect();
+
+        frames.join(", ")
+    }
+}
+
+impl PacketAnalysis for Reassembled {
+    fn short_description(&self) -> String {
+        format!(
+            "REASSEMBLED {}: {} fragments ({}) len {}{}",
+            self.ip_version,
+            self.frames.len(),
+            self.frames_formatted(),
+            self.payload.len(),
+            if self.overlapping { " OVERLAP" } else { "" }
+        )
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        match self.ip_version {
+            Protocol::IPv4 => IPv4::dissect_next_protocol(self.next_protocol, &self.payload),
+            Protocol::IPv6 => IPv6::dissect_next_header(self.next_protocol, &self.payload),
+            _ => None
+        }
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        vec![
+            PacketField::generated("reassembled.ip_version", "IP version", self.ip_version.to_string()),
+            PacketField::generated("reassembled.count", "Fragments", self.frames.len()),
+            PacketField::generated("reassembled.frames", "Frames", self.frames_formatted()),
+            PacketField::generated("reassembled.overlap", "Overlapping fragments", self.overlapping),
+            PacketField::generated("reassembled.proto", "Next level protocol", self.next_protocol.0).with_meaning(self.next_protocol),
+            PacketField::generated("reassembled.length", "Reassembled length", self.payload.len()),
+        ]
+    }
+
+    fn protocol(&self) -> Protocol {
+        Reassembled::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
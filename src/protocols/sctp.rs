@@ -0,0 +1,343 @@
+use std::any::Any;
+
+use crate::enums::address::Address;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+enum SctpChunk {
+    Data {
+        flags: u8,
+        tsn: u32,
+        stream_id: u16,
+        stream_sequence: u16,
+        payload_protocol_id: u32,
+        length: usize,
+    },
+    Init {
+        acknowledgement: bool,
+        initiate_tag: u32,
+        receiver_window: u32,
+        outbound_streams: u16,
+        inbound_streams: u16,
+        initial_tsn: u32,
+    },
+    Sack {
+        cumulative_tsn: u32,
+        receiver_window: u32,
+        gap_blocks: u16,
+        duplicate_tsns: u16,
+    },
+    Heartbeat {
+        acknowledgement: bool,
+        info_length: usize,
+    },
+    Abort {
+        tcb_reflected: bool,
+    },
+    Shutdown {
+        cumulative_tsn: u32,
+    },
+    Other {
+        chunk_type: u8,
+        flags: u8,
+        length: u16,
+    },
+}
+
+pub struct SCTP {
+    source: u16,
+    destination: u16,
+    verification_tag: u32,
+    checksum: u32,
+    chunks: Vec<SctpChunk>,
+    chunk_offsets: Vec<usize>,
+    payload: FrameBuffer
+}
+
+impl SCTP {
+
+    const PROTOCOL: Protocol = Protocol::SCTP;
+    const HEADER_LENGTH: usize = 12;
+    const CHUNK_HEADER_LENGTH: usize = 4;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..SCTP::HEADER_LENGTH)?;
+
+        let mut sctp = SCTP {
+            source: u16::from_be_bytes([header[0], header[1]]),
+            destination: u16::from_be_bytes([header[2], header[3]]),
+            verification_tag: u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+            checksum: u32::from_be_bytes([header[8], header[9], header[10], header[11]]),
+            chunks: Vec::new(),
+            chunk_offsets: Vec::new(),
+            payload: FrameBuffer::default()
+        };
+
+        let mut payload_fragments = Vec::new();
+        let mut offset = SCTP::HEADER_LENGTH;
+
+        while offset + SCTP::CHUNK_HEADER_LENGTH <= packet_data.len() {
+            let chunk_type = packet_data[offset];
+            let flags = packet_data[offset + 1];
+            let length = u16::from_be_bytes([packet_data[offset + 2], packet_data[offset + 3]]);
+
+            if (length as usize) < SCTP::CHUNK_HEADER_LENGTH {
+                break;
+            }
+
+            let value = match packet_data.checked_slice(offset + SCTP::CHUNK_HEADER_LENGTH..offset + length as usize) {
+                Some(value) => value,
+                None => packet_data.slice(offset + SCTP::CHUNK_HEADER_LENGTH..)
+            };
+
+            sctp.chunks.push(SCTP::parse_chunk(chunk_type, flags, length, &value, &mut payload_fragments));
+            sctp.chunk_offsets.push(offset);
+
+            offset += (length as usize + 3) & !3;
+        }
+
+        sctp.payload = FrameBuffer::concat(payload_fragments);
+
+        Some(Box::new(sctp))
+    }
+
+    fn parse_chunk(chunk_type: u8, flags: u8, length: u16, value: &FrameBuffer, payload: &mut Vec<FrameBuffer>) -> SctpChunk {
+        match (chunk_type, value.len()) {
+            (0, 12..) => {
+                payload.push(value.slice(12..));
+
+                SctpChunk::Data {
+                    flags,
+                    tsn: read_u32(value, 0),
+                    stream_id: read_u16(value, 4),
+                    stream_sequence: read_u16(value, 6),
+                    payload_protocol_id: read_u32(value, 8),
+                    length: value.len() - 12,
+                }
+            },
+            (1 | 2, 16..) => SctpChunk::Init {
+                acknowledgement: chunk_type == 2,
+                initiate_tag: read_u32(value, 0),
+                receiver_window: read_u32(value, 4),
+                outbound_streams: read_u16(value, 8),
+                inbound_streams: read_u16(value, 10),
+                initial_tsn: read_u32(value, 12),
+            },
+            (3, 12..) => SctpChunk::Sack {
+                cumulative_tsn: read_u32(value, 0),
+                receiver_window: read_u32(value, 4),
+                gap_blocks: read_u16(value, 8),
+                duplicate_tsns: read_u16(value, 10),
+            },
+            (4 | 5, _) => SctpChunk::Heartbeat {
+                acknowledgement: chunk_type == 5,
+                info_length: value.len(),
+            },
+            (6, _) => SctpChunk::Abort {
+                tcb_reflected: flags & 0x01 != 0,
+            },
+            (7, 4..) => SctpChunk::Shutdown {
+                cumulative_tsn: read_u32(value, 0),
+            },
+            _ => SctpChunk::Other {
+                chunk_type,
+                flags,
+                length,
+            }
+        }
+    }
+
+    fn chunk_name(chunk: &SctpChunk) -> &'static str {
+        match chunk {
+            SctpChunk::Data { .. } => "DATA",
+            SctpChunk::Init { acknowledgement: false, .. } => "INIT",
+            SctpChunk::Init { acknowledgement: true, .. } => "INIT_ACK",
+            SctpChunk::Sack { .. } => "SACK",
+            SctpChunk::Heartbeat { acknowledgement: false, .. } => "HEARTBEAT",
+            SctpChunk::Heartbeat { acknowledgement: true, .. } => "HEARTBEAT_ACK",
+            SctpChunk::Abort { .. } => "ABORT",
+            SctpChunk::Shutdown { .. } => "SHUTDOWN",
+            SctpChunk::Other { chunk_type, .. } => match chunk_type {
+                8 => "SHUTDOWN_ACK",
+                9 => "ERROR",
+                10 => "COOKIE_ECHO",
+                11 => "COOKIE_ACK",
+                14 => "SHUTDOWN_COMPLETE",
+                _ => "UNKNOWN"
+            }
+        }
+    }
+
+    fn chunk_field(chunk: &SctpChunk, offset: usize) -> PacketField {
+        let value = offset + SCTP::CHUNK_HEADER_LENGTH;
+
+        let (chunk_type, length, children) = match chunk {
+            SctpChunk::Data { flags, tsn, stream_id, stream_sequence, payload_protocol_id, length } => (0u8, SCTP::CHUNK_HEADER_LENGTH + 12 + length, vec![
+                PacketField::hex("sctp.chunk_flags", "Flags", *flags, offset + 1, 1),
+                PacketField::new("sctp.data_tsn", "TSN", *tsn, value, 4),
+                PacketField::new("sctp.data_sid", "Stream", *stream_id, value + 4, 2),
+                PacketField::new("sctp.data_ssn", "Stream sequence", *stream_sequence, value + 6, 2),
+                PacketField::new("sctp.data_payload_proto_id", "PPID", *payload_protocol_id, value + 8, 4),
+                PacketField::generated("sctp.data_length", "Data length", *length),
+            ]),
+            SctpChunk::Init { acknowledgement, initiate_tag, receiver_window, outbound_streams, inbound_streams, initial_tsn } => {
+                (if *acknowledgement { 2 } else { 1 }, 20, vec![
+                    PacketField::hex("sctp.initiate_tag", "Initiate tag", *initiate_tag, value, 4),
+                    PacketField::new("sctp.initiate_rwnd", "a_rwnd", *receiver_window, value + 4, 4),
+                    PacketField::new("sctp.initiate_nr_out_streams", "Outbound streams", *outbound_streams, value + 8, 2),
+                    PacketField::new("sctp.initiate_nr_in_streams", "Inbound streams", *inbound_streams, value + 10, 2),
+                    PacketField::new("sctp.initiate_initial_tsn", "Initial TSN", *initial_tsn, value + 12, 4),
+                ])
+            },
+            SctpChunk::Sack { cumulative_tsn, receiver_window, gap_blocks, duplicate_tsns } => (3, 16, vec![
+                PacketField::new("sctp.sack_cumulative_tsn_ack", "Cumulative TSN", *cumulative_tsn, value, 4),
+                PacketField::new("sctp.sack_a_rwnd", "a_rwnd", *receiver_window, value + 4, 4),
+                PacketField::new("sctp.sack_number_of_gap_blocks", "Gap blocks", *gap_blocks, value + 8, 2),
+                PacketField::new("sctp.sack_number_of_duplicated_tsns", "Duplicate TSNs", *duplicate_tsns, value + 10, 2),
+            ]),
+            SctpChunk::Heartbeat { acknowledgement, info_length } => (
+                if *acknowledgement { 5 } else { 4 },
+                SCTP::CHUNK_HEADER_LENGTH + info_length,
+                vec![PacketField::new("sctp.heartbeat_information_length", "Info length", *info_length, value, *info_length)]
+            ),
+            SctpChunk::Abort { tcb_reflected } => (6, SCTP::CHUNK_HEADER_LENGTH, vec![
+                PacketField::new("sctp.abort_t_bit", "TCB reflected", *tcb_reflected, offset + 1, 1),
+            ]),
+            SctpChunk::Shutdown { cumulative_tsn } => (7, 8, vec![
+                PacketField::new("sctp.shutdown_cumulative_tsn_ack", "Cumulative TSN", *cumulative_tsn, value, 4),
+            ]),
+            SctpChunk::Other { chunk_type, flags, length } => (*chunk_type, *length as usize, vec![
+                PacketField::hex("sctp.chunk_flags", "Flags", *flags, offset + 1, 1),
+                PacketField::new("sctp.chunk_length", "Length", *length, offset + 2, 2),
+            ])
+        };
+
+        PacketField::new("sctp.chunk_type", "Chunk", chunk_type, offset, length)
+            .with_meaning(SCTP::chunk_name(chunk))
+            .with_children(children)
+    }
+}
+
+impl PacketAnalysis for SCTP {
+    fn short_description(&self) -> String {
+        let chunks: Vec<&str> = self.chunks.iter().map(SCTP::chunk_name).collect();
+
+        format!("SCTP: {} -> {} [{}] len {}", self.source, self.destination, chunks.join(", "), self.payload.len())
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("sctp.srcport", "Source port", self.source, 0, 2),
+            PacketField::new("sctp.dstport", "Destination port", self.destination, 2, 2),
+            PacketField::hex("sctp.verification_tag", "Verification tag", self.verification_tag, 4, 4),
+            PacketField::hex("sctp.checksum", "Checksum", self.checksum, 8, 4),
+        ];
+
+        for (chunk, offset) in self.chunks.iter().zip(self.chunk_offsets.iter()) {
+            fields.push(SCTP::chunk_field(chunk, *offset));
+        }
+        fields.push(PacketField::generated("sctp.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        SCTP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        format!("{}", self.source)
+    }
+
+    fn destination(&self) -> String {
+        format!("{}", self.destination)
+    }
+
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::Port(self.source))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::Port(self.destination))
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn properties(&self) -> Vec<String> {
+        self.chunks.iter().map(|chunk| SCTP::chunk_name(chunk).to_string()).collect()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMON_HEADER: [u8; 12] = [0x0b, 0x59, 0x0b, 0x59, 0, 0, 0, 1, 0, 0, 0, 0];
+
+    fn data_chunk(tsn: u8, user_data: &[u8]) -> Vec<u8> {
+        let mut chunk = vec![0, 0x03];
+        chunk.extend_from_slice(&((SCTP::CHUNK_HEADER_LENGTH + 12 + user_data.len()) as u16).to_be_bytes());
+        chunk.extend_from_slice(&[0, 0, 0, tsn, 0, 1, 0, tsn, 0, 0, 0, 0]);
+        chunk.extend_from_slice(user_data);
+        chunk.resize((chunk.len() + 3) & !3, 0);
+        chunk
+    }
+
+    fn packet(chunks: &[Vec<u8>]) -> FrameBuffer {
+        let mut packet = COMMON_HEADER.to_vec();
+        chunks.iter().for_each(|chunk| packet.extend_from_slice(chunk));
+        FrameBuffer::from(packet)
+    }
+
+    #[test]
+    fn covers_user_data_in_data_chunk_field() {
+        let sctp = SCTP::new(&packet(&[data_chunk(1, b"hello")])).unwrap();
+        let chunk = sctp.fields().into_iter().find(|field| field.name == "sctp.chunk_type").unwrap();
+
+        assert_eq!((chunk.offset, chunk.length), (12, 21));
+    }
+
+    #[test]
+    fn shares_single_data_chunk_payload_with_packet() {
+        let data = packet(&[data_chunk(1, b"hello")]);
+        let sctp = SCTP::new(&data).unwrap();
+
+        assert_eq!(&sctp.payload()[..], b"hello");
+        assert!(sctp.payload().shares_data(&data));
+        assert_eq!(sctp.payload().offset(), 28);
+    }
+
+    #[test]
+    fn copies_payload_of_several_data_chunks() {
+        let data = packet(&[data_chunk(1, b"hello"), data_chunk(2, b" world")]);
+        let sctp = SCTP::new(&data).unwrap();
+
+        assert_eq!(&sctp.payload()[..], b"hello world");
+        assert!(!sctp.payload().shares_data(&data));
+    }
+}
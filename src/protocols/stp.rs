@@ -0,0 +1,329 @@
+use std::any::Any;
+
+use pnet::util::MacAddr;
+use crate::enums::address::Address;
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+
+struct BridgeId {
+    priority: u16,
+    address: MacAddr,
+}
+
+struct MstiConfiguration {
+    instance: u16,
+    flags: u8,
+    regional_root: BridgeId,
+    internal_path_cost: u32,
+    remaining_hops: u8,
+}
+
+pub struct STP {
+    version: u8,
+    bpdu_type: u8,
+    flags: u8,
+    root_id: Option<BridgeId>,
+    root_path_cost: u32,
+    bridge_id: Option<BridgeId>,
+    port_id: u16,
+    message_age: u16,
+    max_age: u16,
+    hello_time: u16,
+    forward_delay: u16,
+    mst_config_name: Option<String>,
+    mst_revision: u16,
+    msti_configurations: Vec<MstiConfiguration>,
+    payload: FrameBuffer
+}
+
+impl STP {
+
+    const PROTOCOL: Protocol = Protocol::STP;
+    const CONFIGURATION_BPDU_LENGTH: usize = 35;
+    const MSTP_CIST_LENGTH: usize = 64;
+    const MSTI_LENGTH: usize = 16;
+
+    const BPDU_RAPID: u8 = 0x02;
+    const BPDU_TOPOLOGY_CHANGE_NOTIFICATION: u8 = 0x80;
+
+    const FLAG_TOPOLOGY_CHANGE: u8 = 0x01;
+    const FLAG_PROPOSAL: u8 = 0x02;
+    const FLAG_LEARNING: u8 = 0x10;
+    const FLAG_FORWARDING: u8 = 0x20;
+    const FLAG_AGREEMENT: u8 = 0x40;
+    const FLAG_TOPOLOGY_CHANGE_ACK: u8 = 0x80;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..4)?;
+
+        if header[0] != 0 || header[1] != 0 {
+            return None;
+        }
+
+        let mut stp = STP {
+            version: header[2],
+            bpdu_type: header[3],
+            flags: 0,
+            root_id: None,
+            root_path_cost: 0,
+            bridge_id: None,
+            port_id: 0,
+            message_age: 0,
+            max_age: 0,
+            hello_time: 0,
+            forward_delay: 0,
+            mst_config_name: None,
+            mst_revision: 0,
+            msti_configurations: Vec::new(),
+            payload: FrameBuffer::default()
+        };
+
+        if stp.bpdu_type == STP::BPDU_TOPOLOGY_CHANGE_NOTIFICATION {
+            stp.payload = packet_data.slice(4..);
+            return Some(Box::new(stp));
+        }
+
+        let bpdu = packet_data.get(..STP::CONFIGURATION_BPDU_LENGTH)?;
+
+        stp.flags = bpdu[4];
+        stp.root_id = Some(STP::parse_bridge_id(&bpdu[5..13]));
+        stp.root_path_cost = u32::from_be_bytes([bpdu[13], bpdu[14], bpdu[15], bpdu[16]]);
+        stp.bridge_id = Some(STP::parse_bridge_id(&bpdu[17..25]));
+        stp.port_id = u16::from_be_bytes([bpdu[25], bpdu[26]]);
+        stp.message_age = u16::from_be_bytes([bpdu[27], bpdu[28]]);
+        stp.max_age = u16::from_be_bytes([bpdu[29], bpdu[30]]);
+        stp.hello_time = u16::from_be_bytes([bpdu[31], bpdu[32]]);
+        stp.forward_delay = u16::from_be_bytes([bpdu[33], bpdu[34]]);
+
+        let mut offset = STP::CONFIGURATION_BPDU_LENGTH;
+
+        if stp.bpdu_type == STP::BPDU_RAPID {
+            offset += 1;
+        }
+
+        if stp.version >= 3 {
+            if let Some(cist) = packet_data.get(offset + 2..offset + 2 + STP::MSTP_CIST_LENGTH) {
+                let version_3_length = u16::from_be_bytes([packet_data[offset], packet_data[offset + 1]]) as usize;
+
+                stp.mst_config_name = Some(String::from_utf8_lossy(&cist[1..33]).trim_end_matches('\0').to_string());
+                stp.mst_revision = u16::from_be_bytes([cist[33], cist[34]]);
+                stp.msti_configurations = STP::parse_msti_configurations(
+                    packet_data.get(offset + 2 + STP::MSTP_CIST_LENGTH..offset + 2 + version_3_length).unwrap_or(&[])
+                );
+
+                offset = (offset + 2 + version_3_length).min(packet_data.len());
+            }
+        }
+
+        stp.payload = packet_data.checked_slice(offset..).unwrap_or_default();
+
+        Some(Box::new(stp))
+    }
+
+    pub fn is_topology_change_notification(&self) -> bool {
+        self.bpdu_type == STP::BPDU_TOPOLOGY_CHANGE_NOTIFICATION
+    }
+
+    /// The flag stays set on every BPDU for the whole topology change period, not only on the first one
+    pub fn has_topology_change_flag(&self) -> bool {
+        self.flags & STP::FLAG_TOPOLOGY_CHANGE != 0
+    }
+
+    pub fn sender(&self) -> String {
+        match &self.bridge_id {
+            Some(bridge_id) => STP::format_bridge_id(bridge_id),
+            None => String::from("unknown bridge")
+        }
+    }
+
+    fn parse_bridge_id(data: &[u8]) -> BridgeId {
+        BridgeId {
+            priority: u16::from_be_bytes([data[0], data[1]]),
+            address: MacAddr::new(data[2], data[3], data[4], data[5], data[6], data[7]),
+        }
+    }
+
+    fn parse_msti_configurations(data: &[u8]) -> Vec<MstiConfiguration> {
+        data.chunks_exact(STP::MSTI_LENGTH)
+            .map(|msti| {
+                let regional_root = STP::parse_bridge_id(&msti[1..9]);
+
+                MstiConfiguration {
+                    instance: regional_root.priority & 0x0FFF,
+                    flags: msti[0],
+                    regional_root,
+                    internal_path_cost: u32::from_be_bytes([msti[9], msti[10], msti[11], msti[12]]),
+                    remaining_hops: msti[15],
+                }
+            })
+            .collect()
+    }
+
+    fn bridge_id_field(name: &str, label: &str, bridge_id: &BridgeId, offset: usize) -> PacketField {
+        PacketField::new(name, label, STP::format_bridge_id(bridge_id), offset, 8).with_children(vec![
+            PacketField::new(format!("{}.prio", name).as_str(), "Priority", bridge_id.priority, offset, 2),
+            PacketField::new(format!("{}.hw", name).as_str(), "Address", bridge_id.address, offset + 2, 6),
+        ])
+    }
+
+    fn format_bridge_id(bridge_id: &BridgeId) -> String {
+        format!("{}/{}", bridge_id.priority, bridge_id.address)
+    }
+
+    fn version_name(&self) -> &'static str {
+        match self.version {
+            0 => "STP",
+            2 => "RSTP",
+            3 => "MSTP",
+            _ => "Unknown"
+        }
+    }
+
+    fn port_role_name(flags: u8) -> &'static str {
+        match (flags >> 2) & 0x03 {
+            1 => "Alternate/Backup",
+            2 => "Root",
+            3 => "Designated",
+            _ => "Unknown"
+        }
+    }
+
+    fn flag_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+
+        if self.bpdu_type == STP::BPDU_TOPOLOGY_CHANGE_NOTIFICATION {
+            names.push(String::from("TCN"));
+        }
+
+        for (flag, name) in [
+            (STP::FLAG_TOPOLOGY_CHANGE, "TC"),
+            (STP::FLAG_PROPOSAL, "PROPOSAL"),
+            (STP::FLAG_LEARNING, "LEARNING"),
+            (STP::FLAG_FORWARDING, "FORWARDING"),
+            (STP::FLAG_AGREEMENT, "AGREEMENT"),
+            (STP::FLAG_TOPOLOGY_CHANGE_ACK, "TCA"),
+        ] {
+            if self.flags & flag != 0 {
+                names.push(String::from(name));
+            }
+        }
+
+        names
+    }
+}
+
+impl PacketAnalysis for STP {
+    fn short_description(&self) -> String {
+        match (self.bpdu_type, &self.root_id) {
+            (STP::BPDU_TOPOLOGY_CHANGE_NOTIFICATION, _) => format!("{}: topology change notification", self.version_name()),
+            (_, Some(root_id)) => format!(
+                "{}: root {} cost {} port 0x{:04x} [{}]",
+                self.version_name(),
+                STP::format_bridge_id(root_id),
+                self.root_path_cost,
+                self.port_id,
+                self.flag_names().join(", ")
+            ),
+            (_, None) => format!("{}: BPDU type 0x{:02x}", self.version_name(), self.bpdu_type)
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("stp.version", "Version", self.version, 2, 1).with_meaning(self.version_name()),
+            PacketField::hex("stp.type", "BPDU type", self.bpdu_type, 3, 1),
+        ];
+
+        if self.bpdu_type == STP::BPDU_TOPOLOGY_CHANGE_NOTIFICATION {
+            return fields;
+        }
+
+        fields.push(PacketField::hex("stp.flags", "Flags", self.flags, 4, 1).with_meaning(self.flag_names().join(", ")));
+        if self.bpdu_type == STP::BPDU_RAPID {
+            fields.push(PacketField::new("stp.flags.port_role", "Port role", (self.flags >> 2) & 0x03, 4, 1)
+                .with_meaning(STP::port_role_name(self.flags)));
+        }
+        if let Some(root_id) = &self.root_id {
+            fields.push(STP::bridge_id_field("stp.root", "Root bridge ID", root_id, 5));
+        }
+        fields.push(PacketField::new("stp.root.cost", "Root path cost", self.root_path_cost, 13, 4));
+        if let Some(bridge_id) = &self.bridge_id {
+            fields.push(STP::bridge_id_field("stp.bridge", "Bridge ID", bridge_id, 17));
+        }
+        fields.push(PacketField::hex("stp.port", "Port ID", self.port_id, 25, 2));
+        fields.push(PacketField::new("stp.msg_age", "Message age", self.message_age as f64 / 256.0, 27, 2).with_meaning("s"));
+        fields.push(PacketField::new("stp.max_age", "Max age", self.max_age as f64 / 256.0, 29, 2).with_meaning("s"));
+        fields.push(PacketField::new("stp.hello", "Hello time", self.hello_time as f64 / 256.0, 31, 2).with_meaning("s"));
+        fields.push(PacketField::new("stp.forward", "Forward delay", self.forward_delay as f64 / 256.0, 33, 2).with_meaning("s"));
+
+        if let Some(mst_config_name) = &self.mst_config_name {
+            let cist = STP::CONFIGURATION_BPDU_LENGTH + if self.bpdu_type == STP::BPDU_RAPID { 3 } else { 2 };
+
+            fields.push(PacketField::new("mstp.config_name", "MST configuration name", mst_config_name.as_str(), cist + 1, 32));
+            fields.push(PacketField::new("mstp.config_revision_level", "MST revision", self.mst_revision, cist + 33, 2));
+
+            for (idx, msti) in self.msti_configurations.iter().enumerate() {
+                let offset = cist + STP::MSTP_CIST_LENGTH + idx * STP::MSTI_LENGTH;
+
+                fields.push(PacketField::new("mstp.msti", "MSTI", msti.instance, offset, STP::MSTI_LENGTH).with_children(vec![
+                    PacketField::new("mstp.msti.port_role", "Port role", (msti.flags >> 2) & 0x03, offset, 1)
+                        .with_meaning(STP::port_role_name(msti.flags)),
+                    STP::bridge_id_field("mstp.msti.root", "Regional root", &msti.regional_root, offset + 1),
+                    PacketField::new("mstp.msti.root_cost", "Internal path cost", msti.internal_path_cost, offset + 9, 4),
+                    PacketField::new("mstp.msti.remaining_hops", "Remaining hops", msti.remaining_hops, offset + 15, 1),
+                ]));
+            }
+        }
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        STP::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        match &self.bridge_id {
+            Some(bridge_id) => bridge_id.address.to_string(),
+            None => String::new()
+        }
+    }
+
+    fn destination(&self) -> String {
+        match &self.root_id {
+            Some(root_id) => root_id.address.to_string(),
+            None => String::new()
+        }
+    }
+
+    fn source_address(&self) -> Option<Address> {
+        self.bridge_id.as_ref().map(|bridge_id| Address::Mac(bridge_id.address))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        self.root_id.as_ref().map(|root_id| Address::Mac(root_id.address))
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn properties(&self) -> Vec<String> {
+        self.flag_names()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,8 +1,39 @@
+use std::any::Any;
+
 use pnet::packet::Packet;
 use pnet::packet::tcp::TcpPacket;
+use crate::enums::address::Address;
+use crate::enums::checksum_status::ChecksumStatus;
 use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
 
+use crate::protocols::malformed::{Malformed, MalformedReason};
+use crate::services::dissector_registry::{DissectorRegistry, TransportTable};
 use crate::traits::packet_analysis::PacketAnalysis;
+use crate::utils::checksum_util::{checksum_status, pseudo_header_sum, sum_words, CHECKSUM_PARTIAL};
+
+enum TcpOption {
+    EndOfList,
+    NoOperation,
+    MaximumSegmentSize(u16),
+    WindowScale(u8),
+    SackPermitted,
+    Sack(Vec<(u32, u32)>),
+    Timestamps {
+        value: u32,
+        echo_reply: u32,
+    },
+    FastOpen(Vec<u8>),
+    Multipath {
+        subtype: u8,
+        data: Vec<u8>,
+    },
+    Unknown {
+        kind: u8,
+        data: Vec<u8>,
+    },
+}
 
 pub struct TCP {
     source: u16,
@@ -15,16 +46,37 @@ pub struct TCP {
     flags: u8,
     window: u16,
     checksum: u16,
-    payload: Vec<u8>
+    segment_length: usize,
+    segment_sum: u32,
+    checksum_status: ChecksumStatus,
+    options: Vec<TcpOption>,
+    payload: FrameBuffer
 }
 
 impl TCP {
 
     const PROTOCOL: Protocol = Protocol::TCP;
+    const MIN_HEADER_LENGTH: usize = 20;
+    const FLAG_NAMES: [(u8, &'static str); 8] = [
+        (0x02, "SYN"),
+        (0x10, "ACK"),
+        (0x01, "FIN"),
+        (0x04, "RST"),
+        (0x08, "PSH"),
+        (0x20, "URG"),
+        (0x40, "ECE"),
+        (0x80, "CWR"),
+    ];
 
-    pub fn new(packet_data: &[u8]) -> Option<Box<dyn PacketAnalysis>> {
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
         match TcpPacket::new(packet_data) {
             Some(tcp) => {
+                let header_length = tcp.get_data_offset() as usize * 4;
+
+                if header_length < TCP::MIN_HEADER_LENGTH || header_length > packet_data.len() {
+                    return Malformed::new(Protocol::TCP, MalformedReason::BadHeaderLength(header_length), 12, packet_data);
+                }
+
                 Some(Box::new(TCP {
                     source: tcp.get_source(),
                     destination: tcp.get_destination(),
@@ -36,37 +88,228 @@ impl TCP {
                     flags: tcp.get_flags(),
                     window: tcp.get_window(),
                     checksum: tcp.get_checksum(),
-                    payload: tcp.payload().to_vec()
+                    segment_length: packet_data.len(),
+                    segment_sum: sum_words(packet_data),
+                    checksum_status: ChecksumStatus::Unverified(CHECKSUM_PARTIAL),
+                    options: TCP::parse_options(tcp.get_options_raw()),
+                    payload: packet_data.slice(header_length..)
                 }))
             },
-            None => None
+            None => Malformed::new(
+                Protocol::TCP,
+                MalformedReason::TooShort { required: 20, available: packet_data.len() },
+                packet_data.len(),
+                packet_data
+            )
+        }
+    }
+
+    fn parse_options(options_data: &[u8]) -> Vec<TcpOption> {
+        let mut options = Vec::new();
+        let mut offset = 0;
+
+        while offset < options_data.len() {
+            let kind = options_data[offset];
+
+            match kind {
+                0 => {
+                    options.push(TcpOption::EndOfList);
+                    break;
+                },
+                1 => {
+                    options.push(TcpOption::NoOperation);
+                    offset += 1;
+                    continue;
+                },
+                _ => {}
+            }
+
+            let option_length = match options_data.get(offset + 1) {
+                Some(length) if *length >= 2 && offset + *length as usize <= options_data.len() => *length as usize,
+                _ => break
+            };
+
+            options.push(TCP::parse_option(kind, &options_data[offset + 2..offset + option_length]));
+            offset += option_length;
+        }
+
+        options
+    }
+
+    fn parse_option(kind: u8, data: &[u8]) -> TcpOption {
+        match (kind, data.len()) {
+            (2, 2) => TcpOption::MaximumSegmentSize(u16::from_be_bytes([data[0], data[1]])),
+            (3, 1) => TcpOption::WindowScale(data[0]),
+            (4, 0) => TcpOption::SackPermitted,
+            (5, _) => TcpOption::Sack(
+                data.chunks_exact(8)
+                    .map(|block| (
+                        u32::from_be_bytes([block[0], block[1], block[2], block[3]]),
+                        u32::from_be_bytes([block[4], block[5], block[6], block[7]])
+                    ))
+                    .collect()
+            ),
+            (8, 8) => TcpOption::Timestamps {
+                value: u32::from_be_bytes([data[0], data[1], data[2], data[3]]),
+                echo_reply: u32::from_be_bytes([data[4], data[5], data[6], data[7]]),
+            },
+            (30, length) if length > 0 => TcpOption::Multipath { subtype: data[0] >> 4, data: data.to_vec() },
+            (34, _) => TcpOption::FastOpen(data.to_vec()),
+            _ => TcpOption::Unknown { kind, data: data.to_vec() }
+        }
+    }
+
+    fn flag_names(&self) -> Vec<&'static str> {
+        TCP::FLAG_NAMES
+            .iter()
+            .filter(|(mask, _)| self.flags & mask != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    fn option_name(option: &TcpOption) -> &'static str {
+        match option {
+            TcpOption::EndOfList => "EOL",
+            TcpOption::NoOperation => "NOP",
+            TcpOption::MaximumSegmentSize(_) => "MSS",
+            TcpOption::WindowScale(_) => "WS",
+            TcpOption::SackPermitted => "SACK_PERM",
+            TcpOption::Sack(_) => "SACK",
+            TcpOption::Timestamps { .. } => "TS",
+            TcpOption::FastOpen(_) => "TFO",
+            TcpOption::Multipath { .. } => "MPTCP",
+            TcpOption::Unknown { .. } => "UNKNOWN"
+        }
+    }
+
+    fn multipath_subtype_name(subtype: u8) -> &'static str {
+        match subtype {
+            0 => "MP_CAPABLE",
+            1 => "MP_JOIN",
+            2 => "DSS",
+            3 => "ADD_ADDR",
+            4 => "REMOVE_ADDR",
+            5 => "MP_PRIO",
+            6 => "MP_FAIL",
+            7 => "MP_FASTCLOSE",
+            8 => "MP_TCPRST",
+            _ => "Unknown"
+        }
+    }
+
+    fn option_length(option: &TcpOption) -> usize {
+        match option {
+            TcpOption::EndOfList | TcpOption::NoOperation => 1,
+            TcpOption::SackPermitted => 2,
+            TcpOption::WindowScale(_) => 3,
+            TcpOption::MaximumSegmentSize(_) => 4,
+            TcpOption::Timestamps { .. } => 10,
+            TcpOption::Sack(blocks) => 2 + blocks.len() * 8,
+            TcpOption::FastOpen(data) | TcpOption::Multipath { data, .. } | TcpOption::Unknown { data, .. } => 2 + data.len()
         }
     }
+
+    fn option_field(option: &TcpOption, offset: usize) -> PacketField {
+        let name = TCP::option_name(option);
+        let length = TCP::option_length(option);
+
+        let (kind, children) = match option {
+            TcpOption::EndOfList => (0u8, Vec::new()),
+            TcpOption::NoOperation => (1, Vec::new()),
+            TcpOption::SackPermitted => (4, Vec::new()),
+            TcpOption::MaximumSegmentSize(mss) => (2, vec![PacketField::new("tcp.options.mss_val", "MSS", *mss, offset + 2, 2)]),
+            TcpOption::WindowScale(shift) => (3, vec![
+                PacketField::new("tcp.options.wscale.shift", "Shift count", *shift, offset + 2, 1),
+                PacketField::generated("tcp.options.wscale.multiplier", "Multiplier", 1u32 << (*shift).min(14)),
+            ]),
+            TcpOption::Sack(blocks) => (5, blocks
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, (left, right))| vec![
+                    PacketField::new("tcp.options.sack_le", "Left edge", *left, offset + 2 + idx * 8, 4),
+                    PacketField::new("tcp.options.sack_re", "Right edge", *right, offset + 6 + idx * 8, 4),
+                ])
+                .collect()),
+            TcpOption::Timestamps { value, echo_reply } => (8, vec![
+                PacketField::new("tcp.options.timestamp.tsval", "Value", *value, offset + 2, 4),
+                PacketField::new("tcp.options.timestamp.tsecr", "Echo reply", *echo_reply, offset + 6, 4),
+            ]),
+            TcpOption::FastOpen(cookie) if cookie.is_empty() => {
+                (34, vec![PacketField::generated("tcp.options.tfo.request", "Cookie request", true)])
+            },
+            TcpOption::FastOpen(cookie) => {
+                (34, vec![PacketField::new("tcp.options.tfo.cookie", "Cookie", cookie.as_slice(), offset + 2, cookie.len())])
+            },
+            TcpOption::Multipath { subtype, data } => (30, vec![
+                PacketField::new("tcp.options.mptcp.subtype", "Subtype", *subtype, offset + 2, 1)
+                    .with_meaning(TCP::multipath_subtype_name(*subtype)),
+                PacketField::new("tcp.options.mptcp.data", "Data", data.as_slice(), offset + 2, data.len()),
+            ]),
+            TcpOption::Unknown { kind, data } => {
+                (*kind, vec![PacketField::new("tcp.options.data", "Data", data.as_slice(), offset + 2, data.len())])
+            }
+        };
+
+        PacketField::new("tcp.options.kind", "Option", kind, offset, length)
+            .with_meaning(name)
+            .with_children(children)
+    }
 }
 
 impl PacketAnalysis for TCP {
     fn short_description(&self) -> String {
-        format!("TCP: {} -> {} len {}", self.source, self.destination, self.payload.len())
+        format!(
+            "TCP: {} -> {} [{}] len {}",
+            self.source,
+            self.destination,
+            self.flag_names().join(", "),
+            self.payload.len()
+        )
     }
 
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
-        None
+        DissectorRegistry::get().dissect_transport(TransportTable::Tcp, self.source, self.destination, &self.payload)
     }
 
-    fn print_details(&self) {
-        println!("--------- TCP packet ---------");
-        println!("|");
-        println!("| Source port: {}", self.source);
-        println!("| Destination port: {}", self.destination);
-        println!("| Payload length: {}", self.payload_length);
-        println!("| Sequence: {}", self.sequence);
-        println!("| Acknowledgement: {}", self.acknowledgement);
-        println!("| Data offset: {}", self.data_offset);
-        println!("| Reserved: {}", self.reserved);
-        println!("| Flags: {}", self.flags);
-        println!("| Window: {}", self.window);
-        println!("| Checksum: {}", self.checksum);
-        println!("|");
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = vec![
+            PacketField::new("tcp.srcport", "Source port", self.source, 0, 2),
+            PacketField::new("tcp.dstport", "Destination port", self.destination, 2, 2),
+            PacketField::new("tcp.seq", "Sequence", self.sequence, 4, 4),
+            PacketField::new("tcp.ack", "Acknowledgement", self.acknowledgement, 8, 4),
+            PacketField::new("tcp.hdr_len", "Data offset", self.data_offset, 12, 1),
+            PacketField::new("tcp.flags.res", "Reserved", self.reserved, 12, 1),
+            PacketField::hex("tcp.flags", "Flags", self.flags, 13, 1)
+                .with_meaning(self.flag_names().join(", "))
+                .with_children(TCP::FLAG_NAMES
+                    .iter()
+                    .map(|(mask, name)| PacketField::new(
+                        format!("tcp.flags.{}", name.to_lowercase()).as_str(),
+                        name,
+                        self.flags & mask != 0,
+                        13,
+                        1
+                    ))
+                    .collect()),
+            PacketField::new("tcp.window_size", "Window", self.window, 14, 2),
+            PacketField::hex("tcp.checksum", "Checksum", self.checksum, 16, 2).with_meaning(self.checksum_status),
+        ];
+
+        if !self.options.is_empty() {
+            let mut offset = TCP::MIN_HEADER_LENGTH;
+            let mut children = Vec::new();
+
+            for option in &self.options {
+                children.push(TCP::option_field(option, offset));
+                offset += TCP::option_length(option);
+            }
+
+            fields.push(PacketField::group("tcp.options", "Options", TCP::MIN_HEADER_LENGTH, offset - TCP::MIN_HEADER_LENGTH, children));
+        }
+
+        fields.push(PacketField::generated("tcp.len", "Payload length", self.payload_length));
+
+        fields
     }
 
     fn protocol(&self) -> Protocol {
@@ -81,11 +324,46 @@ impl PacketAnalysis for TCP {
         format!("{}", self.destination)
     }
 
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::Port(self.source))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::Port(self.destination))
+    }
+
     fn payload_length(&self) -> usize {
         self.payload.len()
     }
 
-    fn payload(&self) -> &Vec<u8> {
-        self.payload.as_ref()
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn checksum_status(&self) -> Option<ChecksumStatus> {
+        Some(self.checksum_status)
+    }
+
+    fn verify_checksum(&mut self, addresses: Option<(&[u8], &[u8])>, offload: bool) {
+        self.checksum_status = match addresses {
+            Some((source, destination)) => checksum_status(
+                self.segment_sum + pseudo_header_sum(source, destination, 6, self.segment_length),
+                self.checksum,
+                offload
+            ),
+            None => ChecksumStatus::Unverified(CHECKSUM_PARTIAL)
+        };
+    }
+
+    fn properties(&self) -> Vec<String> {
+        self.flag_names()
+            .into_iter()
+            .chain(self.options.iter().map(TCP::option_name))
+            .map(String::from)
+            .collect()
     }
 }
\ No newline at end of file
@@ -0,0 +1,598 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::traits::packet_analysis::PacketAnalysis;
+use crate::utils::x509_util::{CertificateSummary, parse_certificate};
+
+const RECORD_HEADER_LENGTH: usize = 5;
+const MAX_RECORD_LENGTH: usize = 16384 + 2048;
+
+const CONTENT_TYPE_CHANGE_CIPHER_SPEC: u8 = 20;
+const CONTENT_TYPE_HANDSHAKE: u8 = 22;
+const CONTENT_TYPE_APPLICATION_DATA: u8 = 23;
+
+const HANDSHAKE_CLIENT_HELLO: u8 = 1;
+const HANDSHAKE_SERVER_HELLO: u8 = 2;
+const HANDSHAKE_CERTIFICATE: u8 = 11;
+
+const EXTENSION_SERVER_NAME: u16 = 0;
+const EXTENSION_ALPN: u16 = 16;
+const EXTENSION_SUPPORTED_VERSIONS: u16 = 43;
+
+struct TlsRecord {
+    content_type: u8,
+    version: u16,
+    length: u16,
+}
+
+/// Offsets and ranges are relative to the handshake buffer the messages were parsed from
+#[derive(Default)]
+pub struct TlsHandshake {
+    message_types: Vec<u8>,
+    version: Option<u16>,
+    version_offset: usize,
+    cipher_suites: Vec<u16>,
+    cipher_suites_offset: usize,
+    chosen_cipher: Option<u16>,
+    chosen_cipher_offset: usize,
+    server_name: Option<String>,
+    server_name_range: (usize, usize),
+    alpn: Vec<String>,
+    alpn_range: (usize, usize),
+    supported_versions: Vec<u16>,
+    supported_versions_range: (usize, usize),
+    certificates: Vec<((usize, usize), CertificateSummary)>,
+}
+
+pub struct TLS {
+    records: Vec<TlsRecord>,
+    handshake: TlsHandshake,
+    handshake_fragments: Vec<(usize, usize)>,
+    payload: FrameBuffer
+}
+
+impl TLS {
+
+    const PROTOCOL: Protocol = Protocol::TLS;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        if !TLS::is_record_header(packet_data) {
+            return None;
+        }
+
+        let mut records = Vec::new();
+        let mut handshake_data = Vec::new();
+        let mut handshake_fragments = Vec::new();
+        let mut payload_fragments = Vec::new();
+        let mut cipher_changed = false;
+        let mut offset = 0;
+
+        while TLS::is_record_header(&packet_data[offset..]) {
+            let record = TlsRecord {
+                content_type: packet_data[offset],
+                version: read_u16(packet_data, offset + 1)?,
+                length: read_u16(packet_data, offset + 3)?,
+            };
+
+            let fragment_start = offset + RECORD_HEADER_LENGTH;
+            let fragment_end = (fragment_start + record.length as usize).min(packet_data.len());
+            let fragment = packet_data.slice(fragment_start..fragment_end);
+
+            match record.content_type {
+                CONTENT_TYPE_CHANGE_CIPHER_SPEC => cipher_changed = true,
+                CONTENT_TYPE_HANDSHAKE if !cipher_changed => {
+                    handshake_data.extend_from_slice(&fragment);
+                    handshake_fragments.push((fragment_start, fragment.len()));
+                },
+                CONTENT_TYPE_APPLICATION_DATA => payload_fragments.push(fragment),
+                _ => {}
+            }
+
+            records.push(record);
+            offset = fragment_end;
+        }
+
+        Some(Box::new(TLS {
+            records,
+            handshake: TlsHandshake::parse(&handshake_data),
+            handshake_fragments,
+            payload: FrameBuffer::concat(payload_fragments)
+        }))
+    }
+
+    fn is_record_header(data: &[u8]) -> bool {
+        if data.len() < RECORD_HEADER_LENGTH {
+            return false;
+        }
+
+        let length = ((data[3] as usize) << 8) | data[4] as usize;
+
+        (20..=23).contains(&data[0]) && data[1] == 3 && data[2] <= 4 && length <= MAX_RECORD_LENGTH
+    }
+
+    /// Maps an offset in the reassembled handshake buffer back to the record fragment it came from
+    fn handshake_offset(&self, offset: usize) -> usize {
+        let mut start = 0;
+
+        for (fragment_offset, fragment_length) in &self.handshake_fragments {
+            if offset < start + fragment_length {
+                return fragment_offset + offset - start;
+            }
+            start += fragment_length;
+        }
+
+        offset
+    }
+
+    fn content_type_name(content_type: u8) -> &'static str {
+        match content_type {
+            20 => "Change Cipher Spec",
+            21 => "Alert",
+            22 => "Handshake",
+            23 => "Application Data",
+            _ => "Unknown"
+        }
+    }
+}
+
+impl TlsHandshake {
+    pub fn parse(data: &[u8]) -> TlsHandshake {
+        let mut handshake = TlsHandshake::default();
+        let mut offset = 0;
+
+        while offset + 4 <= data.len() {
+            let message_type = data[offset];
+            let length = read_u24(data, offset + 1).unwrap_or(0);
+            let body_start = offset + 4;
+            let body = &data[body_start..(body_start + length).min(data.len())];
+
+            handshake.message_types.push(message_type);
+
+            match message_type {
+                HANDSHAKE_CLIENT_HELLO => handshake.parse_client_hello(body, body_start),
+                HANDSHAKE_SERVER_HELLO => handshake.parse_server_hello(body, body_start),
+                HANDSHAKE_CERTIFICATE => handshake.parse_certificates(body, body_start),
+                _ => None
+            };
+
+            offset = body_start + length;
+        }
+
+        handshake
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.message_types.is_empty()
+    }
+
+    pub fn server_name(&self) -> Option<&String> {
+        self.server_name.as_ref()
+    }
+
+    pub fn summary(&self) -> String {
+        let messages: Vec<&str> = self.message_types
+            .iter()
+            .map(|message_type| TlsHandshake::message_name(*message_type))
+            .collect();
+
+        match &self.server_name {
+            Some(server_name) => format!("{} SNI {}", messages.join(", "), server_name),
+            None => messages.join(", ")
+        }
+    }
+
+    pub fn fields(&self) -> Vec<PacketField> {
+        let messages: Vec<&str> = self.message_types
+            .iter()
+            .map(|message_type| TlsHandshake::message_name(*message_type))
+            .collect();
+
+        let mut fields = vec![PacketField::generated("tls.handshake.type", "Handshake messages", messages.join(", "))];
+
+        if let Some(version) = self.version {
+            fields.push(PacketField::new("tls.handshake.version", "Hello version", version, self.version_offset, 2)
+                .with_meaning(TlsHandshake::version_name(version)));
+        }
+
+        if !self.supported_versions.is_empty() {
+            let versions: Vec<String> = self.supported_versions
+                .iter()
+                .map(|version| TlsHandshake::version_name(*version))
+                .collect();
+            let (offset, length) = self.supported_versions_range;
+            fields.push(PacketField::new("tls.handshake.extensions.supported_versions", "Supported versions", versions.join(", "), offset, length));
+        }
+
+        if let Some(server_name) = &self.server_name {
+            let (offset, length) = self.server_name_range;
+            fields.push(PacketField::new("tls.handshake.extensions_server_name", "Server name (SNI)", server_name.as_str(), offset, length));
+        }
+
+        if !self.alpn.is_empty() {
+            let (offset, length) = self.alpn_range;
+            fields.push(PacketField::new("tls.handshake.extensions_alpn_str", "ALPN", self.alpn.join(", "), offset, length));
+        }
+
+        if let Some(chosen_cipher) = self.chosen_cipher {
+            fields.push(PacketField::new("tls.handshake.ciphersuite", "Chosen cipher suite", chosen_cipher, self.chosen_cipher_offset, 2)
+                .with_meaning(TlsHandshake::cipher_suite_name(chosen_cipher)));
+        }
+
+        if !self.cipher_suites.is_empty() {
+            fields.push(PacketField::new(
+                "tls.handshake.ciphersuites",
+                "Cipher suites",
+                self.cipher_suites.len(),
+                self.cipher_suites_offset,
+                self.cipher_suites.len() * 2
+            ).with_children(
+                self.cipher_suites
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, cipher_suite)| PacketField::new("tls.handshake.ciphersuite", "Cipher suite", *cipher_suite, self.cipher_suites_offset + idx * 2, 2)
+                        .with_meaning(TlsHandshake::cipher_suite_name(*cipher_suite)))
+                    .collect()
+            ));
+        }
+
+        for ((offset, length), certificate) in &self.certificates {
+            let field = |name: &str, label: &str, value: &str, (start, length): (usize, usize)| {
+                PacketField::new(name, label, value, offset + start, length)
+            };
+
+            fields.push(PacketField::group("tls.handshake.certificate", "Certificate", *offset, *length, vec![
+                field("x509.subject", "Subject", certificate.subject.as_str(), certificate.subject_range),
+                field("x509.issuer", "Issuer", certificate.issuer.as_str(), certificate.issuer_range),
+                field("x509.not_before", "Not before", certificate.not_before.as_str(), certificate.not_before_range),
+                field("x509.not_after", "Not after", certificate.not_after.as_str(), certificate.not_after_range),
+            ]));
+        }
+
+        fields
+    }
+
+    fn parse_client_hello(&mut self, body: &[u8], body_start: usize) -> Option<()> {
+        self.version = Some(read_u16(body, 0)?);
+        self.version_offset = body_start;
+
+        let session_id_length = *body.get(34)? as usize;
+        let mut offset = 35 + session_id_length;
+
+        let cipher_suites_length = read_u16(body, offset)? as usize;
+        offset += 2;
+        self.cipher_suites_offset = body_start + offset;
+
+        for idx in (0..cipher_suites_length).step_by(2) {
+            self.cipher_suites.push(read_u16(body, offset + idx)?);
+        }
+        offset += cipher_suites_length;
+
+        let compression_methods_length = *body.get(offset)? as usize;
+        offset += 1 + compression_methods_length;
+
+        self.parse_extensions(body, body_start, offset, true)
+    }
+
+    fn parse_server_hello(&mut self, body: &[u8], body_start: usize) -> Option<()> {
+        self.version = Some(read_u16(body, 0)?);
+        self.version_offset = body_start;
+
+        let session_id_length = *body.get(34)? as usize;
+        let offset = 35 + session_id_length;
+
+        self.chosen_cipher = Some(read_u16(body, offset)?);
+        self.chosen_cipher_offset = body_start + offset;
+
+        self.parse_extensions(body, body_start, offset + 3, false)
+    }
+
+    fn parse_extensions(&mut self, body: &[u8], body_start: usize, offset: usize, client: bool) -> Option<()> {
+        let extensions_length = read_u16(body, offset)? as usize;
+        let mut offset = offset + 2;
+        let end = (offset + extensions_length).min(body.len());
+
+        while offset + 4 <= end {
+            let extension_type = read_u16(body, offset)?;
+            let extension_length = read_u16(body, offset + 2)? as usize;
+            let data_start = offset + 4;
+            let data = body.get(data_start..data_start + extension_length)?;
+
+            match extension_type {
+                EXTENSION_SERVER_NAME => self.parse_server_name(data, body_start + data_start),
+                EXTENSION_ALPN => self.parse_alpn(data, body_start + data_start),
+                EXTENSION_SUPPORTED_VERSIONS => self.parse_supported_versions(data, body_start + data_start, client),
+                _ => None
+            };
+
+            offset = data_start + extension_length;
+        }
+
+        Some(())
+    }
+
+    fn parse_server_name(&mut self, data: &[u8], data_start: usize) -> Option<()> {
+        let list_length = read_u16(data, 0)? as usize;
+        let mut offset = 2;
+
+        while offset + 3 <= (2 + list_length).min(data.len()) {
+            let name_type = data[offset];
+            let name_length = read_u16(data, offset + 1)? as usize;
+            let name = data.get(offset + 3..offset + 3 + name_length)?;
+
+            if name_type == 0 {
+                self.server_name = Some(String::from_utf8_lossy(name).to_string());
+                self.server_name_range = (data_start + offset + 3, name_length);
+            }
+
+            offset += 3 + name_length;
+        }
+
+        Some(())
+    }
+
+    fn parse_alpn(&mut self, data: &[u8], data_start: usize) -> Option<()> {
+        let list_length = read_u16(data, 0)? as usize;
+        let mut offset = 2;
+
+        self.alpn_range = (data_start + offset, list_length.min(data.len() - offset));
+
+        while offset < (2 + list_length).min(data.len()) {
+            let protocol_length = data[offset] as usize;
+            let protocol = data.get(offset + 1..offset + 1 + protocol_length)?;
+
+            self.alpn.push(String::from_utf8_lossy(protocol).to_string());
+            offset += 1 + protocol_length;
+        }
+
+        Some(())
+    }
+
+    fn parse_supported_versions(&mut self, data: &[u8], data_start: usize, client: bool) -> Option<()> {
+        if !client {
+            self.supported_versions.push(read_u16(data, 0)?);
+            self.supported_versions_range = (data_start, 2);
+            return Some(());
+        }
+
+        let list_length = *data.first()? as usize;
+        self.supported_versions_range = (data_start + 1, list_length.min(data.len() - 1));
+
+        for idx in (1..1 + list_length).step_by(2) {
+            self.supported_versions.push(read_u16(data, idx)?);
+        }
+
+        Some(())
+    }
+
+    fn parse_certificates(&mut self, body: &[u8], body_start: usize) -> Option<()> {
+        let list_length = read_u24(body, 0)?;
+        let mut offset = 3;
+
+        while offset + 3 <= (3 + list_length).min(body.len()) {
+            let certificate_length = read_u24(body, offset)?;
+            let certificate = body.get(offset + 3..offset + 3 + certificate_length)?;
+
+            if let Some(summary) = parse_certificate(certificate) {
+                self.certificates.push(((body_start + offset + 3, certificate_length), summary));
+            }
+
+            offset += 3 + certificate_length;
+        }
+
+        Some(())
+    }
+
+    fn message_name(message_type: u8) -> &'static str {
+        match message_type {
+            0 => "HelloRequest",
+            1 => "ClientHello",
+            2 => "ServerHello",
+            4 => "NewSessionTicket",
+            8 => "EncryptedExtensions",
+            11 => "Certificate",
+            12 => "ServerKeyExchange",
+            13 => "CertificateRequest",
+            14 => "ServerHelloDone",
+            15 => "CertificateVerify",
+            16 => "ClientKeyExchange",
+            20 => "Finished",
+            _ => "Encrypted Handshake Message"
+        }
+    }
+
+    fn version_name(version: u16) -> String {
+        match version {
+            0x0300 => String::from("SSL 3.0"),
+            0x0301 => String::from("TLS 1.0"),
+            0x0302 => String::from("TLS 1.1"),
+            0x0303 => String::from("TLS 1.2"),
+            0x0304 => String::from("TLS 1.3"),
+            _ if TlsHandshake::is_grease(version) => String::from("GREASE"),
+            _ => format!("0x{:04x}", version)
+        }
+    }
+
+    fn cipher_suite_name(cipher_suite: u16) -> String {
+        let name = match cipher_suite {
+            0x002F => "TLS_RSA_WITH_AES_128_CBC_SHA",
+            0x0035 => "TLS_RSA_WITH_AES_256_CBC_SHA",
+            0x009C => "TLS_RSA_WITH_AES_128_GCM_SHA256",
+            0x009D => "TLS_RSA_WITH_AES_256_GCM_SHA384",
+            0x00FF => "TLS_EMPTY_RENEGOTIATION_INFO_SCSV",
+            0x1301 => "TLS_AES_128_GCM_SHA256",
+            0x1302 => "TLS_AES_256_GCM_SHA384",
+            0x1303 => "TLS_CHACHA20_POLY1305_SHA256",
+            0xC009 => "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA",
+            0xC00A => "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA",
+            0xC013 => "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA",
+            0xC014 => "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA",
+            0xC02B => "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256",
+            0xC02C => "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+            0xC02F => "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+            0xC030 => "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384",
+            0xCCA8 => "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256",
+            0xCCA9 => "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256",
+            _ if TlsHandshake::is_grease(cipher_suite) => "GREASE",
+            _ => return format!("0x{:04x}", cipher_suite)
+        };
+
+        String::from(name)
+    }
+
+    fn is_grease(value: u16) -> bool {
+        value & 0x0F0F == 0x0A0A && (value >> 8) == (value & 0xFF)
+    }
+}
+
+impl PacketAnalysis for TLS {
+    fn short_description(&self) -> String {
+        if self.handshake.is_empty() {
+            let content_types: Vec<&str> = self.records
+                .iter()
+                .map(|record| TLS::content_type_name(record.content_type))
+                .collect();
+
+            format!("TLS: {} len {}", content_types.join(", "), self.payload.len())
+        } else {
+            format!("TLS: {}", self.handshake.summary())
+        }
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        None
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        let mut fields = Vec::new();
+        let mut offset = 0;
+
+        for record in &self.records {
+            fields.push(
+                PacketField::new("tls.record.content_type", "Record", record.content_type, offset, RECORD_HEADER_LENGTH + record.length as usize)
+                    .with_meaning(TLS::content_type_name(record.content_type))
+                    .with_children(vec![
+                        PacketField::hex("tls.record.version", "Version", record.version, offset + 1, 2)
+                            .with_meaning(TlsHandshake::version_name(record.version)),
+                        PacketField::new("tls.record.length", "Length", record.length, offset + 3, 2),
+                    ])
+            );
+            offset += RECORD_HEADER_LENGTH + record.length as usize;
+        }
+
+        if !self.handshake.is_empty() {
+            fields.extend(self.handshake.fields().iter().map(|field| field.mapped(&|offset| self.handshake_offset(offset))));
+        }
+        fields.push(PacketField::generated("tls.payload_length", "Payload length", self.payload.len()));
+
+        fields
+    }
+
+    fn protocol(&self) -> Protocol {
+        TLS::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        self.handshake.server_name.clone().unwrap_or_default()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(((bytes[0] as u16) << 8) | bytes[1] as u16)
+}
+
+fn read_u24(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..offset + 3)?;
+    Some(((bytes[0] as usize) << 16) | ((bytes[1] as usize) << 8) | bytes[2] as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client_hello(server_name: &str) -> Vec<u8> {
+        let mut server_name_list = vec![0];
+        server_name_list.extend_from_slice(&(server_name.len() as u16).to_be_bytes());
+        server_name_list.extend_from_slice(server_name.as_bytes());
+
+        let mut extension = EXTENSION_SERVER_NAME.to_be_bytes().to_vec();
+        extension.extend_from_slice(&(server_name_list.len() as u16 + 2).to_be_bytes());
+        extension.extend_from_slice(&(server_name_list.len() as u16).to_be_bytes());
+        extension.extend_from_slice(&server_name_list);
+
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0xAB; 32]);
+        body.extend_from_slice(&[0, 0, 2, 0x13, 0x01, 1, 0]);
+        body.extend_from_slice(&(extension.len() as u16).to_be_bytes());
+        body.extend_from_slice(&extension);
+
+        let mut message = vec![HANDSHAKE_CLIENT_HELLO, 0];
+        message.extend_from_slice(&(body.len() as u16).to_be_bytes());
+        message.extend_from_slice(&body);
+        message
+    }
+
+    fn records(message: &[u8], split: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+
+        for fragment in [&message[..split], &message[split..]] {
+            data.extend_from_slice(&[CONTENT_TYPE_HANDSHAKE, 0x03, 0x01]);
+            data.extend_from_slice(&(fragment.len() as u16).to_be_bytes());
+            data.extend_from_slice(fragment);
+        }
+
+        data
+    }
+
+    fn locate(fields: &[PacketField], name: &str) -> Option<(usize, usize)> {
+        fields
+            .iter()
+            .flat_map(|field| field.find(name))
+            .next()
+            .map(|field| (field.offset, field.length))
+    }
+
+    #[test]
+    fn locates_handshake_fields_relative_to_handshake_buffer() {
+        let handshake = TlsHandshake::parse(&client_hello("example.com"));
+        let fields = handshake.fields();
+
+        assert_eq!(handshake.server_name().map(String::as_str), Some("example.com"));
+        assert_eq!(locate(&fields, "tls.handshake.version"), Some((4, 2)));
+        assert_eq!(locate(&fields, "tls.handshake.ciphersuites"), Some((41, 2)));
+        assert_eq!(locate(&fields, "tls.handshake.extensions_server_name"), Some((56, 11)));
+    }
+
+    #[test]
+    fn maps_handshake_fields_across_records() {
+        let data = records(&client_hello("example.com"), 10);
+        let tls = TLS::new(&FrameBuffer::from(data.clone())).unwrap();
+        let fields = tls.fields();
+
+        assert_eq!(tls.destination(), "example.com");
+        assert_eq!(locate(&fields, "tls.handshake.version"), Some((9, 2)));
+        assert_eq!(locate(&fields, "tls.handshake.ciphersuites"), Some((51, 2)));
+
+        let (offset, length) = locate(&fields, "tls.handshake.extensions_server_name").unwrap();
+        assert_eq!(&data[offset..offset + length], b"example.com");
+    }
+}
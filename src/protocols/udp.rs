@@ -1,33 +1,60 @@
+use std::any::Any;
+
 use pnet::packet::Packet;
 use pnet::packet::udp::UdpPacket;
+use crate::enums::address::Address;
+use crate::enums::checksum_status::ChecksumStatus;
 use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
 
+use crate::protocols::malformed::{Malformed, MalformedReason};
+use crate::services::dissector_registry::{DissectorRegistry, TransportTable};
 use crate::traits::packet_analysis::PacketAnalysis;
+use crate::utils::checksum_util::{checksum_status, pseudo_header_sum, sum_words, CHECKSUM_PARTIAL};
 
 pub struct UDP {
     source: u16,
     destination: u16,
     length: u16,
     checksum: u16,
-    payload: Vec<u8>
+    datagram_sum: Option<u32>,
+    checksum_status: ChecksumStatus,
+    payload: FrameBuffer
 }
 
 impl UDP {
 
     const PROTOCOL: Protocol = Protocol::UDP;
+    const HEADER_LENGTH: usize = 8;
 
-    pub fn new(packet_data: &[u8]) -> Option<Box<dyn PacketAnalysis>> {
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
         match UdpPacket::new(packet_data) {
             Some(udp) => {
+                if (udp.get_length() as usize) < UDP::HEADER_LENGTH {
+                    return Malformed::new(Protocol::UDP, MalformedReason::BadHeaderLength(udp.get_length() as usize), 4, packet_data);
+                }
+
+                let datagram_sum = packet_data
+                    .get(..udp.get_length() as usize)
+                    .map(sum_words);
+
                 Some(Box::new(UDP {
                     source: udp.get_source(),
                     destination: udp.get_destination(),
                     length: udp.get_length(),
                     checksum: udp.get_checksum(),
-                    payload: udp.payload().to_vec()
+                    datagram_sum,
+                    checksum_status: ChecksumStatus::Unverified(CHECKSUM_PARTIAL),
+                    payload: packet_data.slice(UDP::HEADER_LENGTH..UDP::HEADER_LENGTH + udp.payload().len())
                 }))
             },
-            None => None
+            None => Malformed::new(
+                Protocol::UDP,
+                MalformedReason::TooShort { required: 8, available: packet_data.len() },
+                packet_data.len(),
+                packet_data
+            )
         }
     }
 }
@@ -38,18 +65,29 @@ impl PacketAnalysis for UDP {
     }
 
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
-        None
+        if self.length as usize > UDP::HEADER_LENGTH + self.payload.len() {
+            return Malformed::new(
+                Protocol::UDP,
+                MalformedReason::TotalLengthExceedsCapture {
+                    declared: self.length as usize,
+                    captured: UDP::HEADER_LENGTH + self.payload.len()
+                },
+                self.payload.len(),
+                &self.payload
+            );
+        }
+
+        DissectorRegistry::get().dissect_transport(TransportTable::Udp, self.source, self.destination, &self.payload)
     }
 
-    fn print_details(&self) {
-        println!("--------- UDP packet ---------");
-        println!("|");
-        println!("| Source port: {}", self.source);
-        println!("| Destination port: {}", self.destination);
-        println!("| Length: {}", self.length);
-        println!("| Checksum: {}", self.checksum);
-        println!("| Payload length: {}", self.payload.len());
-        println!("|");
+    fn fields(&self) -> Vec<PacketField> {
+        vec![
+            PacketField::new("udp.srcport", "Source port", self.source, 0, 2),
+            PacketField::new("udp.dstport", "Destination port", self.destination, 2, 2),
+            PacketField::new("udp.length", "Length", self.length, 4, 2),
+            PacketField::hex("udp.checksum", "Checksum", self.checksum, 6, 2).with_meaning(self.checksum_status),
+            PacketField::generated("udp.payload_length", "Payload length", self.payload.len()),
+        ]
     }
 
     fn protocol(&self) -> Protocol {
@@ -64,11 +102,48 @@ impl PacketAnalysis for UDP {
         format!("{}", self.destination)
     }
 
+    fn source_address(&self) -> Option<Address> {
+        Some(Address::Port(self.source))
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        Some(Address::Port(self.destination))
+    }
+
     fn payload_length(&self) -> usize {
         self.payload.len()
     }
 
-    fn payload(&self) -> &Vec<u8> {
-        self.payload.as_ref()
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn checksum_status(&self) -> Option<ChecksumStatus> {
+        Some(self.checksum_status)
+    }
+
+    fn verify_checksum(&mut self, addresses: Option<(&[u8], &[u8])>, offload: bool) {
+        // A zero checksum means none was computed, which IPv6 does not allow (RFC 8200)
+        let over_ipv6 = addresses.is_some_and(|(source, _)| source.len() == 16);
+
+        self.checksum_status = match (addresses, self.datagram_sum) {
+            _ if self.checksum == 0 && !over_ipv6 => ChecksumStatus::Missing,
+            (Some((source, destination)), Some(datagram_sum)) => {
+                match checksum_status(
+                    datagram_sum + pseudo_header_sum(source, destination, 17, self.length as usize),
+                    self.checksum,
+                    offload
+                ) {
+                    ChecksumStatus::Correct if self.checksum == 0 => ChecksumStatus::Incorrect(0xFFFF),
+                    ChecksumStatus::Incorrect(0) => ChecksumStatus::Incorrect(0xFFFF),
+                    status => status
+                }
+            },
+            _ => ChecksumStatus::Unverified(CHECKSUM_PARTIAL)
+        };
     }
 }
\ No newline at end of file
@@ -0,0 +1,77 @@
+use std::any::Any;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::packet_field::PacketField;
+
+use crate::protocols::ethernet::Ethernet;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub struct VXLAN {
+    flags: u8,
+    network_identifier: u32,
+    payload: FrameBuffer
+}
+
+impl VXLAN {
+
+    const PROTOCOL: Protocol = Protocol::VXLAN;
+    const HEADER_LENGTH: usize = 8;
+    const FLAG_VALID_IDENTIFIER: u8 = 0x08;
+
+    pub fn new(packet_data: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        let header = packet_data.get(..VXLAN::HEADER_LENGTH)?;
+
+        if header[0] & VXLAN::FLAG_VALID_IDENTIFIER == 0 {
+            return None;
+        }
+
+        Some(Box::new(VXLAN {
+            flags: header[0],
+            network_identifier: u32::from_be_bytes([0, header[4], header[5], header[6]]),
+            payload: packet_data.slice(VXLAN::HEADER_LENGTH..)
+        }))
+    }
+}
+
+impl PacketAnalysis for VXLAN {
+    fn short_description(&self) -> String {
+        format!("VXLAN: VNI {} len {}", self.network_identifier, self.payload.len())
+    }
+
+    fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>> {
+        Ethernet::new(&self.payload)
+    }
+
+    fn fields(&self) -> Vec<PacketField> {
+        vec![
+            PacketField::hex("vxlan.flags", "Flags", self.flags, 0, 1),
+            PacketField::new("vxlan.vni", "VXLAN network identifier", self.network_identifier, 4, 3),
+            PacketField::generated("vxlan.payload_length", "Payload length", self.payload.len()),
+        ]
+    }
+
+    fn protocol(&self) -> Protocol {
+        VXLAN::PROTOCOL
+    }
+
+    fn source(&self) -> String {
+        String::new()
+    }
+
+    fn destination(&self) -> String {
+        String::new()
+    }
+
+    fn payload_length(&self) -> usize {
+        self.payload.len()
+    }
+
+    fn payload(&self) -> &FrameBuffer {
+        &self.payload
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,28 +1,346 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr};
 
+use chrono::{DateTime, Local, TimeZone};
 use pcap::Packet as PcapPacket;
 
+use crate::enums::address::Address;
 use crate::enums::protocol::Protocol;
 use crate::models::analyzed_data::AnalyzedData;
+use crate::models::arp_analysis::ArpAnalysis;
 use crate::models::analyzed_traffic::AnalyzedTraffic;
+use crate::models::decode_as_rule::DecodeAsRule;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::igmp_analysis::IgmpAnalysis;
+use crate::models::ip_fragment::FragmentKey;
+use crate::models::neighbor::Neighbor;
+use crate::models::ntp_analysis::{NtpAnalysis, NtpExchange};
+use crate::protocols::arp::ARP;
+use crate::protocols::cdp::CDP;
+use crate::protocols::data::Data;
 use crate::protocols::ethernet::Ethernet;
+use crate::protocols::igmp::IGMP;
+use crate::protocols::lldp::LLDP;
+use crate::protocols::malformed::Malformed;
+use crate::protocols::mpls::MPLS;
+use crate::protocols::ntp::NTP;
+use crate::protocols::reassembled::Reassembled;
+use crate::protocols::stp::STP;
+use crate::services::dissector_registry::DissectorRegistry;
+use crate::services::reassembler::Reassembler;
+use crate::traits::frame_dissector::FrameDissector;
 use crate::traits::packet_analysis::PacketAnalysis;
 
-pub struct Analyzer {}
+pub struct Analyzer {
+    reassembler: Reassembler,
+    decode_as_rules: Vec<DecodeAsRule>,
+    checksum_offload: bool,
+}
 
 
 impl Analyzer {
-    pub fn analyze_packet(raw_packet: &PcapPacket) -> AnalyzedData {
-        let mut packet: Option<Box<dyn PacketAnalysis>> = Ethernet::new(raw_packet.data);
+    pub fn new() -> Analyzer {
+        Analyzer {
+            reassembler: Reassembler::new(),
+            decode_as_rules: Vec::new(),
+            checksum_offload: false,
+        }
+    }
+
+    pub fn analyze_packet(&mut self, raw_packet: &PcapPacket, previous_packets: &mut [AnalyzedData]) -> AnalyzedData {
+        let mut analyzed_data = Analyzer::capture_frame(raw_packet, previous_packets.len() + 1);
+        self.analyze_frame(&mut analyzed_data, previous_packets);
+
+        analyzed_data
+    }
+
+    pub fn analyze_frame(&mut self, analyzed_data: &mut AnalyzedData, previous_packets: &mut [AnalyzedData]) {
+        let frame = analyzed_data.frame.clone();
+        self.dissect(analyzed_data, Ethernet::new(&frame));
+        self.reassemble(analyzed_data, previous_packets);
+        self.verify_checksums(analyzed_data);
+        Analyzer::locate_malformed(analyzed_data);
+    }
+
+    /// Lazily captured frames keep no layers, but their fragments still go through the reassembler.
+    /// The completing frame stores the reassembled datagram so its layers can be rebuilt on demand.
+    pub fn track_fragments(&mut self, analyzed_data: &mut AnalyzedData, previous_packets: &mut [AnalyzedData]) {
+        let frame = analyzed_data.frame.clone();
+        let mut packet = Ethernet::new(&frame);
+
+        while let Some(layer) = packet {
+            let is_last = layer.fragment().is_some() || matches!(layer.source_address(), Some(Address::Port(_)));
+            analyzed_data.packets.push(layer);
+
+            packet = if is_last { None } else { self.next_layer(analyzed_data) };
+        }
+
+        self.reassemble(analyzed_data, previous_packets);
+
+        analyzed_data.reassembled = analyzed_data.packets
+            .iter()
+            .find_map(|packet| packet.as_any().downcast_ref::<Reassembled>())
+            .cloned();
+        analyzed_data.packets.clear();
+    }
+
+    pub fn capture_frame(raw_packet: &PcapPacket, frame_number: usize) -> AnalyzedData {
+        let mut analyzed_data = AnalyzedData::new(frame_number, FrameBuffer::new(raw_packet.data));
+        analyzed_data.timestamp = Analyzer::capture_timestamp(raw_packet);
+        analyzed_data.captured_length = raw_packet.header.caplen as usize;
+        analyzed_data.original_length = raw_packet.header.len as usize;
+
+        analyzed_data
+    }
+
+    fn capture_timestamp(raw_packet: &PcapPacket) -> DateTime<Local> {
+        let seconds = raw_packet.header.ts.tv_sec;
+        let nanoseconds = raw_packet.header.ts.tv_usec as u32 * 1000;
+
+        Local.timestamp_opt(seconds, nanoseconds).single().unwrap_or_else(Local::now)
+    }
+
+    /// Copy of the dissection settings without any reassembly state
+    pub fn detached(&self) -> Analyzer {
+        Analyzer {
+            reassembler: Reassembler::new(),
+            decode_as_rules: self.decode_as_rules.clone(),
+            checksum_offload: self.checksum_offload,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.reassembler.clear();
+    }
+
+    pub fn set_decode_as_rules(&mut self, rules: Vec<DecodeAsRule>, previous_packets: &mut [AnalyzedData]) {
+        self.decode_as_rules = rules;
+
+        for analyzed_data in previous_packets.iter_mut() {
+            let transport_idx = analyzed_data.packets
+                .iter()
+                .position(|packet| packet.protocol() == Protocol::TCP || packet.protocol() == Protocol::UDP);
+
+            if let Some(transport_idx) = transport_idx {
+                analyzed_data.packets.truncate(transport_idx + 1);
+                let packet = self.next_layer(analyzed_data);
+                self.dissect(analyzed_data, packet);
+                self.verify_checksums(analyzed_data);
+                Analyzer::locate_malformed(analyzed_data);
+            }
+        }
+    }
+
+    pub fn set_checksum_offload(&mut self, checksum_offload: bool, previous_packets: &mut [AnalyzedData]) {
+        self.checksum_offload = checksum_offload;
+
+        for analyzed_data in previous_packets.iter_mut() {
+            self.verify_checksums(analyzed_data);
+        }
+    }
+
+    pub fn is_checksum_offload(&self) -> bool {
+        self.checksum_offload
+    }
+
+    fn locate_malformed(analyzed_data: &mut AnalyzedData) {
+        let captured_length = analyzed_data.captured_length;
+        let original_length = analyzed_data.original_length;
+
+        Analyzer::drop_partial_datagram_errors(analyzed_data);
+
+        for packet in analyzed_data.packets.iter_mut() {
+            if packet.protocol() == Protocol::Reassembled {
+                break;
+            }
+
+            let located = match packet.as_any().downcast_ref::<Malformed>() {
+                Some(malformed) if !malformed.is_located() => malformed.locate(captured_length, original_length),
+                _ => continue
+            };
+
+            *packet = located;
+        }
+
+        if captured_length < original_length && !analyzed_data.is_malformed() {
+            let layer = match analyzed_data.packets.last() {
+                Some(packet) => packet.protocol(),
+                None => Protocol::Ethernet
+            };
+
+            analyzed_data.packets.push(Malformed::truncated(layer, captured_length, original_length));
+        }
+    }
+
+    /// A non-final fragment carries only part of the datagram, so the length of the enclosed header cannot match
+    fn drop_partial_datagram_errors(analyzed_data: &mut AnalyzedData) {
+        let fragment_layer = analyzed_data.packets
+            .iter()
+            .take_while(|packet| packet.protocol() != Protocol::Reassembled)
+            .find(|packet| packet.fragment().is_some_and(|fragment| fragment.more_fragments))
+            .map(|packet| packet.protocol());
+
+        let fragment_layer = match fragment_layer {
+            Some(fragment_layer) => fragment_layer,
+            None => return
+        };
+
+        let malformed_idx = analyzed_data.packets.iter().position(|packet| {
+            match packet.as_any().downcast_ref::<Malformed>() {
+                Some(malformed) => malformed.is_length_mismatch() && malformed.layer() != fragment_layer,
+                None => false
+            }
+        });
+
+        if let Some(malformed_idx) = malformed_idx {
+            analyzed_data.packets.truncate(malformed_idx);
+
+            if let Some(leftover) = analyzed_data.packets.last().and_then(|last| Data::new(last.payload())) {
+                analyzed_data.packets.push(leftover);
+            }
+        }
+    }
+
+    fn verify_checksums(&self, analyzed_data: &mut AnalyzedData) {
+        let mut addresses: Option<(Vec<u8>, Vec<u8>)> = None;
+        let mut partial = false;
+
+        for packet in analyzed_data.packets.iter_mut() {
+            if packet.protocol() == Protocol::IPv4 || packet.protocol() == Protocol::IPv6 {
+                addresses = match (packet.source_address(), packet.destination_address()) {
+                    (Some(Address::IPv4(source)), Some(Address::IPv4(destination))) => Some((source.octets().to_vec(), destination.octets().to_vec())),
+                    (Some(Address::IPv6(source)), Some(Address::IPv6(destination))) => Some((source.octets().to_vec(), destination.octets().to_vec())),
+                    _ => None
+                };
+                partial = packet.fragment().is_some();
+            } else if packet.fragment().is_some() {
+                partial = true;
+            } else if packet.protocol() == Protocol::Reassembled {
+                partial = false;
+            }
+
+            let packet_addresses = match &addresses {
+                Some((source, destination)) if !partial => Some((source.as_slice(), destination.as_slice())),
+                _ => None
+            };
 
-        let mut analyzed_data = AnalyzedData::new();
+            packet.verify_checksum(packet_addresses, self.checksum_offload);
+        }
+    }
 
+    fn dissect(&self, analyzed_data: &mut AnalyzedData, mut packet: Option<Box<dyn PacketAnalysis>>) {
         while packet.is_some() {
             analyzed_data.packets.push(packet.unwrap());
-            packet = analyzed_data.packets.last().unwrap().next_protocol();
+            packet = self.next_layer(analyzed_data);
         }
 
-        analyzed_data
+        let leftover = match analyzed_data.packets.last() {
+            Some(last) if last.protocol() != Protocol::Malformed && last.protocol() != Protocol::Data => Data::new(last.payload()),
+            _ => None
+        };
+
+        if let Some(leftover) = leftover {
+            analyzed_data.packets.push(leftover);
+        }
+    }
+
+    fn next_layer(&self, analyzed_data: &AnalyzedData) -> Option<Box<dyn PacketAnalysis>> {
+        let layer = analyzed_data.packets.last()?;
+
+        self.decode_as(analyzed_data).or_else(|| layer.next_protocol())
+    }
+
+    fn decode_as(&self, analyzed_data: &AnalyzedData) -> Option<Box<dyn PacketAnalysis>> {
+        let transport = analyzed_data.packets.last()?;
+
+        if self.decode_as_rules.is_empty() || (transport.protocol() != Protocol::TCP && transport.protocol() != Protocol::UDP) {
+            return None;
+        }
+
+        let ports: Vec<u16> = [transport.source_address(), transport.destination_address()]
+            .iter()
+            .filter_map(|address| match address {
+                Some(Address::Port(port)) => Some(*port),
+                _ => None
+            })
+            .collect();
+
+        let addresses: Vec<IpAddr> = match analyzed_data.packets
+            .iter()
+            .rev()
+            .find(|packet| packet.protocol() == Protocol::IPv4 || packet.protocol() == Protocol::IPv6) {
+            Some(ip_layer) => [ip_layer.source_address(), ip_layer.destination_address()]
+                .iter()
+                .filter_map(|address| match address {
+                    Some(Address::IPv4(address)) => Some(IpAddr::V4(*address)),
+                    Some(Address::IPv6(address)) => Some(IpAddr::V6(*address)),
+                    _ => None
+                })
+                .collect(),
+            None => Vec::new()
+        };
+
+        let rule = self.decode_as_rules
+            .iter()
+            .filter(|rule| rule.matches(transport.protocol(), &addresses, &ports))
+            .max_by_key(|rule| rule.address.is_some())?;
+
+        DissectorRegistry::get().dissect_as(rule.protocol, transport.payload())
+    }
+
+    fn reassemble(&mut self, analyzed_data: &mut AnalyzedData, previous_packets: &mut [AnalyzedData]) {
+        let mut ip_layer_idx = None;
+        let mut fragment = None;
+
+        for (idx, packet) in analyzed_data.packets.iter().enumerate() {
+            if packet.protocol() == Protocol::IPv4 || packet.protocol() == Protocol::IPv6 {
+                ip_layer_idx = Some(idx);
+            }
+
+            if let Some(ip_fragment) = packet.fragment() {
+                fragment = Some((idx, ip_fragment));
+                break;
+            }
+        }
+
+        let (fragment_idx, fragment, ip_layer) = match (fragment, ip_layer_idx) {
+            (Some((fragment_idx, fragment)), Some(ip_layer_idx)) => (fragment_idx, fragment, &analyzed_data.packets[ip_layer_idx]),
+            _ => return
+        };
+
+        let key = FragmentKey {
+            ip_version: ip_layer.protocol(),
+            source: ip_layer.source(),
+            destination: ip_layer.destination(),
+            identification: fragment.identification,
+            protocol: fragment.next_protocol.0,
+        };
+
+        let reassembled = self.reassembler.process(key, fragment, analyzed_data.frame_number, analyzed_data.timestamp);
+
+        if let Some((frames, reassembled)) = reassembled {
+            for frame_number in frames {
+                if frame_number != analyzed_data.frame_number {
+                    if let Some(previous_packet) = previous_packets.get_mut(frame_number - 1) {
+                        previous_packet.reassembled_in = Some(analyzed_data.frame_number);
+                    }
+                }
+            }
+
+            analyzed_data.packets.truncate(fragment_idx + 1);
+            self.dissect(analyzed_data, Some(reassembled));
+        }
+    }
+
+    fn dissect_reassembled(&self, analyzed_data: &mut AnalyzedData, reassembled: &Reassembled) {
+        let fragment_idx = analyzed_data.packets
+            .iter()
+            .position(|packet| packet.fragment().is_some());
+
+        if let Some(fragment_idx) = fragment_idx {
+            analyzed_data.packets.truncate(fragment_idx + 1);
+            self.dissect(analyzed_data, Some(Box::new(reassembled.clone())));
+        }
     }
 
     pub fn analyze_traffic(packets: &Vec<AnalyzedData>) -> AnalyzedTraffic {
@@ -39,7 +357,14 @@ impl Analyzer {
         }
 
         let mut protocol_usages: HashMap<Protocol, usize> = HashMap::new();
+        let mut mpls_label_usages: HashMap<u32, usize> = HashMap::new();
+        let mut topology_changes: Vec<String> = Vec::new();
+        let mut topology_change_flags: HashMap<String, bool> = HashMap::new();
         let total_packets: usize = packets.len();
+        let mut multicast_packets: usize = 0;
+        let mut broadcast_packets: usize = 0;
+        let mut invalid_checksum_packets: usize = 0;
+        let mut malformed_packets: usize = 0;
         let sniffing_duration: u64 = Analyzer::get_sniffing_duration(packets);
         let mut average_packet_size: usize = 0;
         let average_packets_per_second: f64 = total_packets as f64 / sniffing_duration as f64;
@@ -47,10 +372,10 @@ impl Analyzer {
         let mut min_packet_size: usize = usize::MAX;
 
         for packet in packets {
-            let packet_size = if packet.packets.len() > 0 {
-                packet.packets[0].payload_length()
-            } else {
-                0
+            let layers = packet.layers();
+            let packet_size = match layers.first() {
+                Some(first) => first.payload_length(),
+                None => 0
             };
 
             if packet_size > max_packet_size {
@@ -63,10 +388,48 @@ impl Analyzer {
 
             average_packet_size += packet_size;
 
-            for inner_packet in &packet.packets {
+            if let Some(ethernet) = layers.first().and_then(|first| first.as_any().downcast_ref::<Ethernet>()) {
+                if ethernet.is_broadcast() {
+                    broadcast_packets += 1;
+                } else if ethernet.is_multicast() {
+                    multicast_packets += 1;
+                }
+            }
+
+            if layers.iter().any(|inner_packet| inner_packet.checksum_status().is_some_and(|status| status.is_incorrect())) {
+                invalid_checksum_packets += 1;
+            }
+
+            if packet.is_malformed() {
+                malformed_packets += 1;
+            }
+
+            for inner_packet in layers.iter() {
                 let protocol = inner_packet.protocol();
                 let usage = protocol_usages.entry(protocol).or_insert(0);
                 *usage += 1;
+
+                if let Some(mpls) = inner_packet.as_any().downcast_ref::<MPLS>() {
+                    for label in mpls.labels() {
+                        let usage = mpls_label_usages.entry(label.label).or_insert(0);
+                        *usage += 1;
+                    }
+                }
+
+                if let Some(stp) = inner_packet.as_any().downcast_ref::<STP>() {
+                    let topology_change_started = match stp.is_topology_change_notification() {
+                        true => true,
+                        false => {
+                            let flag = stp.has_topology_change_flag();
+                            let was_flagged = topology_change_flags.insert(stp.sender(), flag).unwrap_or(false);
+                            flag && !was_flagged
+                        }
+                    };
+
+                    if topology_change_started {
+                        topology_changes.push(format!("#{}: {} from {}", packet.frame_number, stp.short_description(), stp.sender()));
+                    }
+                }
             }
         }
 
@@ -81,6 +444,141 @@ impl Analyzer {
             max_packet_size,
             min_packet_size,
         )
+        .with_mpls_label_usages(mpls_label_usages)
+        .with_topology_changes(topology_changes)
+        .with_multicast_packets(multicast_packets)
+        .with_broadcast_packets(broadcast_packets)
+        .with_invalid_checksum_packets(invalid_checksum_packets)
+        .with_malformed_packets(malformed_packets)
+    }
+
+    pub fn analyze_arp(packets: &Vec<AnalyzedData>) -> ArpAnalysis {
+        let mut arp_analysis = ArpAnalysis::new();
+        let mut pending_requests: HashSet<(Ipv4Addr, Ipv4Addr)> = HashSet::new();
+
+        for packet in packets {
+            for inner_packet in packet.layers().iter() {
+                let arp = match inner_packet.as_any().downcast_ref::<ARP>() {
+                    Some(arp) => arp,
+                    None => continue
+                };
+
+                if arp.is_request() && !arp.is_gratuitous() {
+                    pending_requests.insert((arp.sender_proto_addr(), arp.target_proto_addr()));
+                }
+
+                if arp.is_reply() && !arp.is_gratuitous() {
+                    let request = (arp.target_proto_addr(), arp.sender_proto_addr());
+
+                    if !pending_requests.remove(&request) {
+                        arp_analysis.add_unsolicited_reply(arp.sender_proto_addr(), arp.sender_hw_addr(), packet.frame_number);
+                    }
+                }
+
+                if !arp.sender_proto_addr().is_unspecified() {
+                    arp_analysis.observe_binding(arp.sender_proto_addr(), arp.sender_hw_addr(), packet.frame_number);
+                }
+            }
+        }
+
+        arp_analysis
+    }
+
+    pub fn analyze_neighbors(packets: &Vec<AnalyzedData>) -> Vec<Neighbor> {
+        let mut neighbors: Vec<Neighbor> = Vec::new();
+
+        for packet in packets {
+            for inner_packet in packet.layers().iter() {
+                let neighbor = if let Some(lldp) = inner_packet.as_any().downcast_ref::<LLDP>() {
+                    lldp.neighbor()
+                } else if let Some(cdp) = inner_packet.as_any().downcast_ref::<CDP>() {
+                    cdp.neighbor()
+                } else {
+                    continue;
+                };
+
+                neighbors.retain(|known| {
+                    known.protocol != neighbor.protocol
+                        || known.device_id != neighbor.device_id
+                        || known.port_id != neighbor.port_id
+                });
+                neighbors.push(neighbor);
+            }
+        }
+
+        neighbors
+    }
+
+    pub fn analyze_ntp(packets: &Vec<AnalyzedData>) -> NtpAnalysis {
+        let mut requests: HashMap<u64, (&AnalyzedData, String, String)> = HashMap::new();
+        let mut exchanges: Vec<NtpExchange> = Vec::new();
+
+        for packet in packets {
+            let mut addresses = (String::new(), String::new());
+
+            for inner_packet in packet.layers().iter() {
+                if inner_packet.protocol() == Protocol::IPv4 || inner_packet.protocol() == Protocol::IPv6 {
+                    addresses = (inner_packet.source(), inner_packet.destination());
+                }
+
+                let ntp = match inner_packet.as_any().downcast_ref::<NTP>() {
+                    Some(ntp) => ntp,
+                    None => continue
+                };
+
+                if ntp.mode() == NTP::MODE_CLIENT {
+                    requests.insert(ntp.transmit_timestamp(), (packet, addresses.0.clone(), addresses.1.clone()));
+                    continue;
+                }
+
+                if ntp.mode() != NTP::MODE_SERVER {
+                    continue;
+                }
+
+                if let Some((request, client, server)) = requests.remove(&ntp.origin_timestamp()) {
+                    // T1 is the client's clock, T2 and T3 the server's, the round trip is measured on the capture clock
+                    let t1 = NTP::timestamp_to_seconds(ntp.origin_timestamp());
+                    let t2 = NTP::timestamp_to_seconds(ntp.receive_timestamp());
+                    let t3 = NTP::timestamp_to_seconds(ntp.transmit_timestamp());
+                    let round_trip = NTP::datetime_to_seconds(&packet.timestamp) - NTP::datetime_to_seconds(&request.timestamp);
+                    let delay = round_trip - (t3 - t2);
+
+                    exchanges.push(NtpExchange {
+                        request_frame: request.frame_number,
+                        response_frame: packet.frame_number,
+                        client,
+                        server,
+                        stratum: ntp.stratum(),
+                        delay,
+                        offset: (t2 - t1) - delay / 2.0,
+                    });
+                }
+            }
+        }
+
+        NtpAnalysis::new(exchanges, requests.len())
+    }
+
+    pub fn analyze_igmp(packets: &Vec<AnalyzedData>) -> IgmpAnalysis {
+        let mut igmp_analysis = IgmpAnalysis::new();
+
+        for packet in packets {
+            let mut host = String::new();
+
+            for inner_packet in packet.layers().iter() {
+                if inner_packet.protocol() == Protocol::IPv4 {
+                    host = inner_packet.source();
+                }
+
+                if let Some(igmp) = inner_packet.as_any().downcast_ref::<IGMP>() {
+                    for (group, joined) in igmp.membership_changes() {
+                        igmp_analysis.record_membership(packet.frame_number, packet.timestamp, host.clone(), group, joined);
+                    }
+                }
+            }
+        }
+
+        igmp_analysis
     }
 
     fn get_sniffing_duration(packets: &Vec<AnalyzedData>) -> u64 {
@@ -91,4 +589,96 @@ impl Analyzer {
     }
 
 
-}
\ No newline at end of file
+}
+
+impl FrameDissector for Analyzer {
+    fn dissect_frame(&self, analyzed_data: &AnalyzedData) -> Vec<Box<dyn PacketAnalysis>> {
+        let mut dissected = AnalyzedData::new(analyzed_data.frame_number, analyzed_data.frame.clone());
+        dissected.captured_length = analyzed_data.captured_length;
+        dissected.original_length = analyzed_data.original_length;
+
+        self.dissect(&mut dissected, Ethernet::new(&analyzed_data.frame));
+        if let Some(reassembled) = &analyzed_data.reassembled {
+            self.dissect_reassembled(&mut dissected, reassembled);
+        }
+        self.verify_checksums(&mut dissected);
+        Analyzer::locate_malformed(&mut dissected);
+
+        dissected.packets
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::models::dissection_cache::DissectionCache;
+    use crate::utils::checksum_util::{fold, sum_words};
+
+    use super::*;
+
+    fn fragment(offset: usize, more_fragments: bool, data: &[u8]) -> Vec<u8> {
+        let flags_and_offset = ((more_fragments as u16) << 13) | (offset / 8) as u16;
+
+        let mut header = vec![0x45, 0x00];
+        header.extend_from_slice(&((20 + data.len()) as u16).to_be_bytes());
+        header.extend_from_slice(&[0x12, 0x34]);
+        header.extend_from_slice(&flags_and_offset.to_be_bytes());
+        header.extend_from_slice(&[64, 17, 0, 0, 192, 168, 0, 1, 192, 168, 0, 2]);
+
+        let checksum = !fold(sum_words(&header));
+        header[10..12].copy_from_slice(&checksum.to_be_bytes());
+
+        let mut frame = vec![0x02, 0, 0, 0, 0, 0x02, 0x02, 0, 0, 0, 0, 0x01, 0x08, 0x00];
+        frame.extend_from_slice(&header);
+        frame.extend_from_slice(data);
+        frame
+    }
+
+    fn fragmented_datagram(timestamp: DateTime<Local>) -> Vec<AnalyzedData> {
+        let mut datagram = vec![0x9c, 0x40, 0x9c, 0x41, 0x00, 0x20, 0x00, 0x00];
+        datagram.extend((0..24).map(|byte| byte as u8));
+
+        [fragment(0, true, &datagram[..16]), fragment(16, false, &datagram[16..])]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, frame)| {
+                let mut analyzed_data = AnalyzedData::new(idx + 1, FrameBuffer::from(frame));
+                analyzed_data.timestamp = timestamp;
+                analyzed_data
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lazy_dissection_reassembles_like_eager_dissection() {
+        let timestamp = Local::now();
+        let mut eager = Analyzer::new();
+        let mut eager_packets = Vec::new();
+
+        for mut analyzed_data in fragmented_datagram(timestamp) {
+            eager.analyze_frame(&mut analyzed_data, &mut eager_packets);
+            eager_packets.push(analyzed_data);
+        }
+
+        let mut lazy = Analyzer::new();
+        let dissection_cache = Rc::new(DissectionCache::new(Box::new(lazy.detached())));
+        let mut lazy_packets = Vec::new();
+
+        for mut analyzed_data in fragmented_datagram(timestamp) {
+            lazy.track_fragments(&mut analyzed_data, &mut lazy_packets);
+            analyzed_data.dissection_cache = Some(dissection_cache.clone());
+            lazy_packets.push(analyzed_data);
+        }
+
+        let protocols: Vec<Protocol> = eager_packets[1].layers().iter().map(|packet| packet.protocol()).collect();
+        assert!(protocols.contains(&Protocol::Reassembled) && protocols.contains(&Protocol::UDP));
+        assert_eq!(eager_packets[0].reassembled_in, Some(2));
+
+        for (eager_data, lazy_data) in eager_packets.iter().zip(lazy_packets.iter()) {
+            assert!(lazy_data.packets.is_empty());
+            assert_eq!(lazy_data.reassembled_in, eager_data.reassembled_in);
+            assert_eq!(lazy_data.get_info(), eager_data.get_info());
+            assert_eq!(lazy_data.get_export(), eager_data.get_export());
+        }
+    }
+}
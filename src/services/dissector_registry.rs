@@ -0,0 +1,189 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::protocols::arp::ARP;
+use crate::protocols::cdp::CDP;
+use crate::protocols::dns::DNS;
+use crate::protocols::ethernet::Ethernet;
+use crate::protocols::geneve::Geneve;
+use crate::protocols::gre::GRE;
+use crate::protocols::http::HTTP;
+use crate::protocols::icmp::ICMP;
+use crate::protocols::igmp::IGMP;
+use crate::protocols::ipv4::IPv4;
+use crate::protocols::ipv6::IPv6;
+use crate::protocols::ipv6_extension::IPv6Extension;
+use crate::protocols::lldp::LLDP;
+use crate::protocols::mpls::MPLS;
+use crate::protocols::ntp::NTP;
+use crate::protocols::quic::QUIC;
+use crate::protocols::sctp::SCTP;
+use crate::protocols::stp::STP;
+use crate::protocols::tcp::TCP;
+use crate::protocols::tls::TLS;
+use crate::protocols::udp::UDP;
+use crate::protocols::vxlan::VXLAN;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub type Dissector = fn(&FrameBuffer) -> Option<Box<dyn PacketAnalysis>>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DissectorKey {
+    EtherType(u16),
+    IpProtocol(u8),
+    IPv6NextHeader(u8),
+    LlcSap(u8),
+    Snap(u32, u16),
+    TcpPort(u16),
+    UdpPort(u16),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TransportTable {
+    Tcp,
+    Udp,
+}
+
+struct Heuristic {
+    table: TransportTable,
+    priority: u8,
+    dissector: Dissector,
+}
+
+pub struct DissectorRegistry {
+    dissectors: HashMap<DissectorKey, Dissector>,
+    heuristics: Vec<Heuristic>,
+    application_dissectors: Vec<(Protocol, Dissector)>,
+}
+
+impl DissectorRegistry {
+    pub fn get() -> &'static DissectorRegistry {
+        static REGISTRY: OnceLock<DissectorRegistry> = OnceLock::new();
+
+        REGISTRY.get_or_init(|| {
+            let mut registry = DissectorRegistry {
+                dissectors: HashMap::new(),
+                heuristics: Vec::new(),
+                application_dissectors: Vec::new(),
+            };
+
+            registry.register_dissectors();
+            registry
+        })
+    }
+
+    fn register_dissectors(&mut self) {
+        self.register(DissectorKey::EtherType(0x0800), IPv4::new);
+        self.register(DissectorKey::EtherType(0x86DD), IPv6::new);
+        self.register(DissectorKey::EtherType(0x0806), ARP::new);
+        self.register(DissectorKey::EtherType(0x6558), Ethernet::new);
+        self.register(DissectorKey::EtherType(0x8847), |data| MPLS::new(false, data));
+        self.register(DissectorKey::EtherType(0x8848), |data| MPLS::new(true, data));
+        self.register(DissectorKey::EtherType(0x88CC), LLDP::new);
+
+        self.register(DissectorKey::LlcSap(0x42), STP::new);
+        self.register(DissectorKey::Snap(0x00000C, 0x2000), CDP::new);
+
+        self.register(DissectorKey::IpProtocol(1), |data| ICMP::new(Protocol::ICMP, data));
+        self.register(DissectorKey::IpProtocol(2), IGMP::new);
+        self.register(DissectorKey::IpProtocol(4), IPv4::new);
+        self.register(DissectorKey::IpProtocol(6), TCP::new);
+        self.register(DissectorKey::IpProtocol(17), UDP::new);
+        self.register(DissectorKey::IpProtocol(41), IPv6::new);
+        self.register(DissectorKey::IpProtocol(47), GRE::new);
+        self.register(DissectorKey::IpProtocol(132), SCTP::new);
+
+        self.register(DissectorKey::IPv6NextHeader(0), |data| IPv6Extension::new(Protocol::IPv6HopByHop, data));
+        self.register(DissectorKey::IPv6NextHeader(43), |data| IPv6Extension::new(Protocol::IPv6Routing, data));
+        self.register(DissectorKey::IPv6NextHeader(44), |data| IPv6Extension::new(Protocol::IPv6Fragment, data));
+        self.register(DissectorKey::IPv6NextHeader(50), |data| IPv6Extension::new(Protocol::ESP, data));
+        self.register(DissectorKey::IPv6NextHeader(51), |data| IPv6Extension::new(Protocol::AH, data));
+        self.register(DissectorKey::IPv6NextHeader(58), |data| ICMP::new(Protocol::ICMPv6, data));
+        self.register(DissectorKey::IPv6NextHeader(60), |data| IPv6Extension::new(Protocol::IPv6DestinationOptions, data));
+
+        self.register(DissectorKey::TcpPort(80), HTTP::new);
+        self.register(DissectorKey::TcpPort(443), TLS::new);
+        self.register(DissectorKey::UdpPort(53), DNS::new);
+        self.register(DissectorKey::UdpPort(123), NTP::new);
+        self.register(DissectorKey::UdpPort(443), QUIC::new);
+        self.register(DissectorKey::UdpPort(5353), DNS::new);
+        self.register(DissectorKey::UdpPort(4789), VXLAN::new);
+        self.register(DissectorKey::UdpPort(6081), Geneve::new);
+
+        self.register_heuristic(TransportTable::Tcp, 20, HTTP::new);
+        self.register_heuristic(TransportTable::Tcp, 10, TLS::new);
+
+        self.register_application(Protocol::HTTP, HTTP::new);
+        self.register_application(Protocol::TLS, TLS::new);
+        self.register_application(Protocol::QUIC, QUIC::new);
+        self.register_application(Protocol::NTP, NTP::new);
+        self.register_application(Protocol::DNS, DNS::new);
+        self.register_application(Protocol::VXLAN, VXLAN::new);
+        self.register_application(Protocol::Geneve, Geneve::new);
+    }
+
+    fn register(&mut self, key: DissectorKey, dissector: Dissector) {
+        self.dissectors.insert(key, dissector);
+    }
+
+    fn register_heuristic(&mut self, table: TransportTable, priority: u8, dissector: Dissector) {
+        self.heuristics.push(Heuristic { table, priority, dissector });
+        self.heuristics.sort_by_key(|heuristic| Reverse(heuristic.priority));
+    }
+
+    fn register_application(&mut self, protocol: Protocol, dissector: Dissector) {
+        self.application_dissectors.push((protocol, dissector));
+    }
+
+    pub fn application_protocols(&self) -> Vec<Protocol> {
+        self.application_dissectors.iter().map(|(protocol, _)| *protocol).collect()
+    }
+
+    pub fn dissect_as(&self, protocol: Protocol, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        if payload.is_empty() {
+            return None;
+        }
+
+        self.application_dissectors
+            .iter()
+            .find(|(application, _)| *application == protocol)
+            .and_then(|(_, dissector)| dissector(payload))
+    }
+
+    pub fn dissect(&self, key: DissectorKey, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        match self.dissectors.get(&key) {
+            Some(dissector) => dissector(payload),
+            None => None
+        }
+    }
+
+    pub fn dissect_ipv6_next_header(&self, next_header: u8, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        self.dissect(DissectorKey::IPv6NextHeader(next_header), payload)
+            .or_else(|| self.dissect(DissectorKey::IpProtocol(next_header), payload))
+    }
+
+    pub fn dissect_transport(&self, table: TransportTable, source: u16, destination: u16, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        if payload.is_empty() {
+            return None;
+        }
+
+        let key = |port: u16| match table {
+            TransportTable::Tcp => DissectorKey::TcpPort(port),
+            TransportTable::Udp => DissectorKey::UdpPort(port),
+        };
+
+        self.dissect(key(source.min(destination)), payload)
+            .or_else(|| self.dissect(key(source.max(destination)), payload))
+            .or_else(|| self.dissect_heuristics(table, payload))
+    }
+
+    pub fn dissect_heuristics(&self, table: TransportTable, payload: &FrameBuffer) -> Option<Box<dyn PacketAnalysis>> {
+        self.heuristics
+            .iter()
+            .filter(|heuristic| heuristic.table == table)
+            .find_map(|heuristic| (heuristic.dissector)(payload))
+    }
+}
@@ -1,4 +1,6 @@
 pub(crate) mod analyzer;
 pub(crate) mod runner;
 pub(crate) mod sniffer;
-pub(crate) mod packet_viewer;
\ No newline at end of file
+pub(crate) mod packet_viewer;
+pub(crate) mod reassembler;
+pub(crate) mod dissector_registry;
\ No newline at end of file
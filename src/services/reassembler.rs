@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::offset::Local;
+use pnet::packet::ip::IpNextHeaderProtocol;
+
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::ip_fragment::{FragmentKey, IpFragment};
+use crate::protocols::reassembled::Reassembled;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+struct FragmentBuffer {
+    fragments: Vec<(usize, FrameBuffer)>,
+    frames: Vec<usize>,
+    total_length: Option<usize>,
+    next_protocol: IpNextHeaderProtocol,
+    overlapping: bool,
+    last_seen: DateTime<Local>,
+}
+
+pub struct Reassembler {
+    buffers: HashMap<FragmentKey, FragmentBuffer>,
+}
+
+impl Reassembler {
+
+    const TIMEOUT_SECONDS: i64 = 30;
+
+    pub fn new() -> Reassembler {
+        Reassembler {
+            buffers: HashMap::new(),
+        }
+    }
+
+    pub fn process(
+        &mut self,
+        key: FragmentKey,
+        fragment: IpFragment,
+        frame_number: usize,
+        timestamp: DateTime<Local>
+    ) -> Option<(Vec<usize>, Box<dyn PacketAnalysis>)> {
+        self.expire(timestamp);
+
+        let buffer = self.buffers.entry(key.clone()).or_insert(FragmentBuffer {
+            fragments: Vec::new(),
+            frames: Vec::new(),
+            total_length: None,
+            next_protocol: fragment.next_protocol,
+            overlapping: false,
+            last_seen: timestamp,
+        });
+
+        buffer.add_fragment(fragment, frame_number, timestamp);
+
+        if !buffer.is_complete() {
+            return None;
+        }
+
+        let mut buffer = self.buffers.remove(&key)?;
+        buffer.frames.sort();
+
+        Some((
+            buffer.frames.clone(),
+            Reassembled::new(
+                key.ip_version,
+                buffer.next_protocol,
+                buffer.frames.clone(),
+                buffer.overlapping,
+                buffer.assemble()
+            )
+        ))
+    }
+
+    pub fn clear(&mut self) {
+        self.buffers.clear();
+    }
+
+    fn expire(&mut self, now: DateTime<Local>) {
+        self.buffers.retain(|_, buffer| (now - buffer.last_seen).num_seconds() < Reassembler::TIMEOUT_SECONDS);
+    }
+}
+
+impl FragmentBuffer {
+    fn add_fragment(&mut self, fragment: IpFragment, frame_number: usize, timestamp: DateTime<Local>) {
+        let start = fragment.offset;
+        let end = start + fragment.data.len();
+
+        for (existing_start, existing_data) in &self.fragments {
+            let existing_end = existing_start + existing_data.len();
+
+            if start < existing_end && *existing_start < end {
+                self.overlapping = true;
+            }
+        }
+
+        if !fragment.more_fragments {
+            self.total_length = Some(end);
+        }
+
+        self.fragments.push((start, fragment.data));
+        self.frames.push(frame_number);
+        self.last_seen = timestamp;
+    }
+
+    fn is_complete(&self) -> bool {
+        let total_length = match self.total_length {
+            Some(total_length) => total_length,
+            None => return false
+        };
+
+        let mut ranges: Vec<(usize, usize)> = self.fragments
+            .iter()
+            .map(|(start, data)| (*start, start + data.len()))
+            .collect();
+        ranges.sort();
+
+        let mut covered = 0;
+
+        for (start, end) in ranges {
+            if start > covered {
+                return false;
+            }
+            covered = covered.max(end);
+        }
+
+        covered >= total_length
+    }
+
+    fn assemble(&self) -> FrameBuffer {
+        let total_length = self.total_length.unwrap_or(0);
+        let mut datagram = vec![0u8; total_length];
+        let mut filled = vec![false; total_length];
+
+        for (start, data) in &self.fragments {
+            for (idx, byte) in data.iter().enumerate() {
+                let position = start + idx;
+
+                if position < total_length && !filled[position] {
+                    datagram[position] = *byte;
+                    filled[position] = true;
+                }
+            }
+        }
+
+        FrameBuffer::from(datagram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::enums::protocol::Protocol;
+
+    use super::*;
+
+    fn key(ip_version: Protocol) -> FragmentKey {
+        FragmentKey {
+            ip_version,
+            source: String::from("source"),
+            destination: String::from("destination"),
+            identification: 0x1234,
+            protocol: 17,
+        }
+    }
+
+    fn fragment(offset: usize, more_fragments: bool, data: &[u8]) -> IpFragment {
+        IpFragment {
+            identification: 0x1234,
+            offset,
+            more_fragments,
+            next_protocol: IpNextHeaderProtocol(17),
+            data: FrameBuffer::from(data.to_vec()),
+        }
+    }
+
+    fn feed(
+        reassembler: &mut Reassembler,
+        ip_version: Protocol,
+        fragments: Vec<(usize, IpFragment, DateTime<Local>)>
+    ) -> Option<(Vec<usize>, Box<dyn PacketAnalysis>)> {
+        let mut result = None;
+
+        for (frame_number, fragment, timestamp) in fragments {
+            assert!(result.is_none(), "datagram completed before fragment #{}", frame_number);
+            result = reassembler.process(key(ip_version), fragment, frame_number, timestamp);
+        }
+
+        result
+    }
+
+    #[test]
+    fn reassembles_in_order_fragments() {
+        for ip_version in [Protocol::IPv4, Protocol::IPv6] {
+            let now = Local::now();
+            let (frames, datagram) = feed(&mut Reassembler::new(), ip_version, vec![
+                (1, fragment(0, true, &[0, 1, 2, 3, 4, 5, 6, 7]), now),
+                (2, fragment(8, true, &[8, 9, 10, 11, 12, 13, 14, 15]), now),
+                (3, fragment(16, false, &[16, 17]), now),
+            ]).unwrap();
+
+            assert_eq!(frames, vec![1, 2, 3]);
+            assert_eq!(&datagram.payload()[..], &(0..18).collect::<Vec<u8>>()[..]);
+            assert!(!datagram.short_description().ends_with("OVERLAP"));
+        }
+    }
+
+    #[test]
+    fn reassembles_out_of_order_fragments() {
+        for ip_version in [Protocol::IPv4, Protocol::IPv6] {
+            let now = Local::now();
+            let (frames, datagram) = feed(&mut Reassembler::new(), ip_version, vec![
+                (5, fragment(16, false, &[16, 17]), now),
+                (7, fragment(0, true, &[0, 1, 2, 3, 4, 5, 6, 7]), now),
+                (6, fragment(8, true, &[8, 9, 10, 11, 12, 13, 14, 15]), now),
+            ]).unwrap();
+
+            assert_eq!(frames, vec![5, 6, 7]);
+            assert_eq!(&datagram.payload()[..], &(0..18).collect::<Vec<u8>>()[..]);
+        }
+    }
+
+    #[test]
+    fn keeps_first_received_bytes_of_overlapping_fragments() {
+        for ip_version in [Protocol::IPv4, Protocol::IPv6] {
+            let now = Local::now();
+            let (frames, datagram) = feed(&mut Reassembler::new(), ip_version, vec![
+                (1, fragment(0, true, &[0, 1, 2, 3, 4, 5, 6, 7]), now),
+                (2, fragment(4, true, &[0xAA, 0xAA, 0xAA, 0xAA, 8, 9, 10, 11]), now),
+                (3, fragment(12, false, &[12, 13]), now),
+            ]).unwrap();
+
+            assert_eq!(frames, vec![1, 2, 3]);
+            assert_eq!(&datagram.payload()[..], &(0..14).collect::<Vec<u8>>()[..]);
+            assert!(datagram.short_description().ends_with("OVERLAP"));
+        }
+    }
+
+    #[test]
+    fn drops_fragments_after_timeout() {
+        for ip_version in [Protocol::IPv4, Protocol::IPv6] {
+            let start = Local::now();
+            let expired = start + Duration::seconds(Reassembler::TIMEOUT_SECONDS);
+            let mut reassembler = Reassembler::new();
+
+            let result = feed(&mut reassembler, ip_version, vec![
+                (1, fragment(0, true, &[0, 1, 2, 3, 4, 5, 6, 7]), start),
+                (2, fragment(8, false, &[8, 9]), expired),
+            ]);
+            assert!(result.is_none());
+
+            let (frames, datagram) = reassembler
+                .process(key(ip_version), fragment(0, true, &[0, 1, 2, 3, 4, 5, 6, 7]), 3, expired)
+                .unwrap();
+
+            assert_eq!(frames, vec![2, 3]);
+            assert_eq!(datagram.payload().len(), 10);
+        }
+    }
+
+    #[test]
+    fn keeps_fragments_within_timeout() {
+        let start = Local::now();
+        let (frames, _) = feed(&mut Reassembler::new(), Protocol::IPv4, vec![
+            (1, fragment(0, true, &[0, 1, 2, 3, 4, 5, 6, 7]), start),
+            (2, fragment(8, false, &[8, 9]), start + Duration::seconds(Reassembler::TIMEOUT_SECONDS - 1)),
+        ]).unwrap();
+
+        assert_eq!(frames, vec![1, 2]);
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 
+use crate::controllers::decode_as_controller::DecodeAsController;
 use crate::controllers::device_controller::DeviceController;
 use crate::controllers::filter_controller::FilterController;
 use crate::controllers::operation_controller::OperationController;
@@ -11,6 +12,7 @@ use crate::services::sniffer::Sniffer;
 pub struct Runner {
     pub device_controller: DeviceController,
     pub filter_controller: FilterController,
+    pub decode_as_controller: DecodeAsController,
     pub sniffer: Sniffer,
     pub packet_viewer: PacketViewer,
     pub running: Arc<AtomicBool>,
@@ -21,10 +23,16 @@ impl Runner {
         running: Arc<AtomicBool>,
         sniffing_activated: Arc<AtomicBool>
     ) -> Runner {
+        let decode_as_controller = DecodeAsController::new();
+        let mut sniffer = Sniffer::new(sniffing_activated);
+
+        sniffer.set_decode_as_rules(decode_as_controller.get_rules().clone());
+
         Runner {
             device_controller: DeviceController::new(),
             filter_controller: FilterController::new(),
-            sniffer: Sniffer::new(sniffing_activated),
+            decode_as_controller,
+            sniffer,
             packet_viewer: PacketViewer::new(),
             running,
         }
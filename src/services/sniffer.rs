@@ -1,22 +1,37 @@
+use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 
-use pcap::{Active, Capture, Device, Error, Packet};
+use pcap::{Active, Capture, Device, Error, Linktype, Packet};
 
 use crate::models::analyzed_data::AnalyzedData;
 use crate::models::analyzed_traffic::AnalyzedTraffic;
+use crate::models::arp_analysis::ArpAnalysis;
+use crate::models::decode_as_rule::DecodeAsRule;
+use crate::models::dissection_cache::DissectionCache;
+use crate::models::igmp_analysis::IgmpAnalysis;
+use crate::models::neighbor::Neighbor;
+use crate::models::ntp_analysis::NtpAnalysis;
 use crate::services::analyzer::Analyzer;
 
 pub struct Sniffer {
     sniffed_packets: Vec<AnalyzedData>,
+    analyzer: Analyzer,
+    dissection_cache: Rc<DissectionCache>,
+    lazy_dissection: bool,
     sniffing_activated: Arc<AtomicBool>
 }
 
 impl Sniffer {
     pub fn new(sniffing_activated: Arc<AtomicBool>) -> Sniffer {
+        let analyzer = Analyzer::new();
+
         Sniffer {
             sniffed_packets: Vec::new(),
+            dissection_cache: Rc::new(DissectionCache::new(Box::new(analyzer.detached()))),
+            analyzer,
+            lazy_dissection: false,
             sniffing_activated
         }
     }
@@ -30,18 +45,88 @@ impl Sniffer {
         println!("Traffic analysis finished");
     }
 
+    pub fn load_file(&mut self, path: &str) {
+        let mut capture = match Capture::from_file(path) {
+            Ok(capture) => capture,
+            Err(error) => {
+                println!("Error while opening capture file: {}", error);
+                return;
+            }
+        };
+
+        if capture.get_datalink() != Linktype::ETHERNET {
+            println!("Unsupported link type {:?}, only Ethernet captures can be loaded", capture.get_datalink());
+            return;
+        }
+
+        let previously_loaded = self.sniffed_packets.len();
+
+        loop {
+            match capture.next_packet() {
+                Ok(packet) => self.store_packet(&packet),
+                Err(Error::NoMorePackets) => break,
+                Err(error) => {
+                    println!("Error while reading packet: {}", error);
+                    break;
+                }
+            }
+        }
+
+        println!("Loaded {} frames from {}", self.sniffed_packets.len() - previously_loaded, path);
+    }
+
     pub fn get_sniffed_packets(&self) -> &Vec<AnalyzedData> {
         &self.sniffed_packets
     }
 
     pub fn clear_sniffed_packets(&mut self) {
         self.sniffed_packets.clear();
+        self.analyzer.reset();
+        self.dissection_cache.clear();
+    }
+
+    pub fn set_decode_as_rules(&mut self, rules: Vec<DecodeAsRule>) {
+        self.analyzer.set_decode_as_rules(rules, &mut self.sniffed_packets);
+        self.dissection_cache.set_dissector(Box::new(self.analyzer.detached()));
+    }
+
+    pub fn set_checksum_offload(&mut self, checksum_offload: bool) {
+        self.analyzer.set_checksum_offload(checksum_offload, &mut self.sniffed_packets);
+        self.dissection_cache.set_dissector(Box::new(self.analyzer.detached()));
+    }
+
+    pub fn is_checksum_offload(&self) -> bool {
+        self.analyzer.is_checksum_offload()
+    }
+
+    pub fn set_lazy_dissection(&mut self, lazy_dissection: bool) {
+        self.lazy_dissection = lazy_dissection;
+    }
+
+    pub fn is_lazy_dissection(&self) -> bool {
+        self.lazy_dissection
     }
 
     pub fn get_traffic_analysis(&self) -> AnalyzedTraffic {
         Analyzer::analyze_traffic(&self.sniffed_packets)
     }
 
+    pub fn get_arp_analysis(&self) -> ArpAnalysis {
+        Analyzer::analyze_arp(&self.sniffed_packets)
+    }
+
+    pub fn get_neighbors(&self) -> Vec<Neighbor> {
+        Analyzer::analyze_neighbors(&self.sniffed_packets)
+    }
+
+    pub fn get_ntp_analysis(&self) -> NtpAnalysis {
+        Analyzer::analyze_ntp(&self.sniffed_packets)
+    }
+
+    pub fn get_igmp_analysis(&self) -> IgmpAnalysis {
+        Analyzer::analyze_igmp(&self.sniffed_packets)
+    }
+
     fn init_sniffing(& mut self, sniffing_device: &Device) {
         let capture = self.get_capture(&sniffing_device);
 
@@ -74,9 +159,31 @@ impl Sniffer {
     }
 
     fn handle_sniffed_packet(&mut self, packet: &Packet) {
-        let anayzed_data = Analyzer::analyze_packet(packet);
-        println!("{}", anayzed_data.get_info());
+        self.store_packet(packet);
+
+        let stored = match self.sniffed_packets.last() {
+            Some(stored) => stored,
+            None => return
+        };
+
+        if self.lazy_dissection {
+            println!("[{}] Frame {}: {} bytes", stored.timestamp.format("%Y-%m-%d %H:%M:%S"), stored.frame_number, stored.original_length);
+        } else {
+            println!("{}", stored.get_info());
+        }
+    }
+
+    fn store_packet(&mut self, packet: &Packet) {
+        if self.lazy_dissection {
+            let mut captured_frame = Analyzer::capture_frame(packet, self.sniffed_packets.len() + 1);
+            self.analyzer.track_fragments(&mut captured_frame, &mut self.sniffed_packets);
+            captured_frame.dissection_cache = Some(self.dissection_cache.clone());
+
+            self.sniffed_packets.push(captured_frame);
+            return;
+        }
 
+        let anayzed_data = self.analyzer.analyze_packet(packet, &mut self.sniffed_packets);
         self.sniffed_packets.push(anayzed_data);
     }
 }
\ No newline at end of file
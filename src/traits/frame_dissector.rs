@@ -0,0 +1,6 @@
+use crate::models::analyzed_data::AnalyzedData;
+use crate::traits::packet_analysis::PacketAnalysis;
+
+pub trait FrameDissector {
+    fn dissect_frame(&self, analyzed_data: &AnalyzedData) -> Vec<Box<dyn PacketAnalysis>>;
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
 pub(crate) mod packet_analysis;
 pub(crate) mod runner_operation;
-pub(crate) mod packet_filter;
\ No newline at end of file
+pub(crate) mod packet_filter;
+pub(crate) mod frame_dissector;
\ No newline at end of file
@@ -1,12 +1,51 @@
+use std::any::Any;
+
+use crate::enums::address::Address;
+use crate::enums::checksum_status::ChecksumStatus;
 use crate::enums::protocol::Protocol;
+use crate::models::frame_buffer::FrameBuffer;
+use crate::models::ip_fragment::IpFragment;
+use crate::models::packet_field::PacketField;
 
 pub trait PacketAnalysis {
     fn short_description(&self) -> String;
     fn next_protocol(&self) -> Option<Box<dyn PacketAnalysis>>;
-    fn print_details(&self);
+    fn fields(&self) -> Vec<PacketField>;
     fn protocol(&self) -> Protocol;
     fn source(&self) -> String;
     fn destination(&self) -> String;
     fn payload_length(&self) -> usize;
-    fn payload(&self) -> &Vec<u8>;
+    fn payload(&self) -> &FrameBuffer;
+    fn as_any(&self) -> &dyn Any;
+
+    fn print_details(&self) {
+        println!("--------- {} packet ---------", self.protocol());
+        println!("|");
+        for field in self.fields() {
+            field.print(0);
+        }
+        println!("|");
+    }
+
+    fn source_address(&self) -> Option<Address> {
+        None
+    }
+
+    fn destination_address(&self) -> Option<Address> {
+        None
+    }
+
+    fn properties(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn fragment(&self) -> Option<IpFragment> {
+        None
+    }
+
+    fn checksum_status(&self) -> Option<ChecksumStatus> {
+        None
+    }
+
+    fn verify_checksum(&mut self, _addresses: Option<(&[u8], &[u8])>, _offload: bool) {}
 }
\ No newline at end of file
@@ -0,0 +1,210 @@
+use crate::enums::checksum_status::ChecksumStatus;
+
+pub const CHECKSUM_OFFLOAD: &str = "offload";
+pub const CHECKSUM_PARTIAL: &str = "partial data";
+
+pub fn sum_words(data: &[u8]) -> u32 {
+    data.chunks(2)
+        .map(|word| match word {
+            [high, low] => u16::from_be_bytes([*high, *low]) as u32,
+            [high] => (*high as u32) << 8,
+            _ => 0
+        })
+        .sum()
+}
+
+pub fn pseudo_header_sum(source: &[u8], destination: &[u8], protocol: u8, length: usize) -> u32 {
+    sum_words(source) + sum_words(destination) + protocol as u32 + (length as u32 >> 16) + (length as u32 & 0xFFFF)
+}
+
+pub fn fold(mut sum: u32) -> u16 {
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+
+    sum as u16
+}
+
+pub fn checksum_status(sum: u32, checksum: u16, offload: bool) -> ChecksumStatus {
+    if offload {
+        return ChecksumStatus::Unverified(CHECKSUM_OFFLOAD);
+    }
+
+    if fold(sum) == 0xFFFF {
+        ChecksumStatus::Correct
+    } else {
+        ChecksumStatus::Incorrect(!fold(sum - checksum as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use crate::enums::protocol::Protocol;
+    use crate::models::frame_buffer::FrameBuffer;
+    use crate::protocols::icmp::ICMP;
+    use crate::protocols::ipv4::IPv4;
+    use crate::protocols::tcp::TCP;
+    use crate::protocols::udp::UDP;
+    use crate::traits::packet_analysis::PacketAnalysis;
+
+    use super::*;
+
+    const IPV4_HEADER: &str = "45000073000040004011b861c0a80001c0a800c7";
+    const TCP_SEGMENT: &str = "c000005000000001000000005002faf0d73e0000474554";
+    const UDP_DATAGRAM_V4: &str = "14e914e9000d101768656c6c6f";
+    const UDP_DATAGRAM_V6: &str = "14e914e9000d952b68656c6c6f";
+    const ICMP_ECHO_REQUEST: &str = "0800192d0001000170696e67";
+    const ICMPV6_ECHO_REQUEST: &str = "8000a3e10001000170696e67";
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len()).step_by(2).map(|idx| u8::from_str_radix(&value[idx..idx + 2], 16).unwrap()).collect()
+    }
+
+    fn corrupt_checksum(mut packet: Vec<u8>, checksum_offset: usize) -> Vec<u8> {
+        packet[checksum_offset] ^= 0xFF;
+        packet
+    }
+
+    fn clear_checksum(mut packet: Vec<u8>, offset: usize) -> Vec<u8> {
+        packet[offset] = 0;
+        packet[offset + 1] = 0;
+        packet
+    }
+
+    fn ipv4_addresses() -> ([u8; 4], [u8; 4]) {
+        (Ipv4Addr::new(192, 168, 0, 1).octets(), Ipv4Addr::new(192, 168, 0, 199).octets())
+    }
+
+    fn ipv6_addresses() -> ([u8; 16], [u8; 16]) {
+        ("fe80::1".parse::<Ipv6Addr>().unwrap().octets(), "fe80::2".parse::<Ipv6Addr>().unwrap().octets())
+    }
+
+    fn verified(mut packet: Box<dyn PacketAnalysis>, source: &[u8], destination: &[u8]) -> Option<ChecksumStatus> {
+        packet.verify_checksum(Some((source, destination)), false);
+        packet.checksum_status()
+    }
+
+    #[test]
+    fn sums_words_padding_odd_length() {
+        assert_eq!(sum_words(&[0x12, 0x34, 0x56, 0x78]), 0x1234 + 0x5678);
+        assert_eq!(sum_words(&[0x12, 0x34, 0x56]), 0x1234 + 0x5600);
+        assert_eq!(sum_words(&[]), 0);
+    }
+
+    #[test]
+    fn folds_carries_back_into_sum() {
+        assert_eq!(fold(0x1FFFE), 0xFFFF);
+        assert_eq!(fold(0x2_0001), 0x0003);
+        assert_eq!(fold(0x1234), 0x1234);
+    }
+
+    #[test]
+    fn sums_ipv4_pseudo_header() {
+        let (source, destination) = ipv4_addresses();
+
+        assert_eq!(pseudo_header_sum(&source, &destination, 17, 13), 0xC0A8 + 0x0001 + 0xC0A8 + 0x00C7 + 17 + 13);
+    }
+
+    #[test]
+    fn sums_ipv6_pseudo_header_with_32_bit_length() {
+        let (source, destination) = ipv6_addresses();
+
+        assert_eq!(pseudo_header_sum(&source, &destination, 58, 0x1_0002), 0xFE80 + 0x0001 + 0xFE80 + 0x0002 + 58 + 0x0001 + 0x0002);
+    }
+
+    #[test]
+    fn reports_correct_and_incorrect_sums() {
+        let header = hex(IPV4_HEADER);
+
+        assert_eq!(checksum_status(sum_words(&header), 0xB861, false), ChecksumStatus::Correct);
+        assert_eq!(checksum_status(sum_words(&corrupt_checksum(header.clone(), 10)), 0x4761, false), ChecksumStatus::Incorrect(0xB861));
+        assert_eq!(checksum_status(sum_words(&header), 0xB861, true), ChecksumStatus::Unverified(CHECKSUM_OFFLOAD));
+    }
+
+    #[test]
+    fn verifies_ipv4_header_checksum() {
+        let good = IPv4::new(&FrameBuffer::from(hex(IPV4_HEADER))).unwrap();
+        let bad = IPv4::new(&FrameBuffer::from(corrupt_checksum(hex(IPV4_HEADER), 10))).unwrap();
+
+        assert_eq!(good.checksum_status(), Some(ChecksumStatus::Correct));
+        assert_eq!(bad.checksum_status(), Some(ChecksumStatus::Incorrect(0xB861)));
+    }
+
+    #[test]
+    fn verifies_tcp_checksum() {
+        let (source, destination) = ipv4_addresses();
+        let good = TCP::new(&FrameBuffer::from(hex(TCP_SEGMENT))).unwrap();
+        let bad = TCP::new(&FrameBuffer::from(corrupt_checksum(hex(TCP_SEGMENT), 16))).unwrap();
+
+        assert_eq!(verified(good, &source, &destination), Some(ChecksumStatus::Correct));
+        assert_eq!(verified(bad, &source, &destination), Some(ChecksumStatus::Incorrect(0xD73E)));
+    }
+
+    #[test]
+    fn verifies_udp_checksum_over_ipv4() {
+        let (source, destination) = ipv4_addresses();
+        let good = UDP::new(&FrameBuffer::from(hex(UDP_DATAGRAM_V4))).unwrap();
+        let bad = UDP::new(&FrameBuffer::from(corrupt_checksum(hex(UDP_DATAGRAM_V4), 6))).unwrap();
+
+        assert_eq!(verified(good, &source, &destination), Some(ChecksumStatus::Correct));
+        assert_eq!(verified(bad, &source, &destination), Some(ChecksumStatus::Incorrect(0x1017)));
+    }
+
+    #[test]
+    fn verifies_udp_checksum_over_ipv6() {
+        let (source, destination) = ipv6_addresses();
+        let good = UDP::new(&FrameBuffer::from(hex(UDP_DATAGRAM_V6))).unwrap();
+        let bad = UDP::new(&FrameBuffer::from(corrupt_checksum(hex(UDP_DATAGRAM_V6), 6))).unwrap();
+
+        assert_eq!(verified(good, &source, &destination), Some(ChecksumStatus::Correct));
+        assert_eq!(verified(bad, &source, &destination), Some(ChecksumStatus::Incorrect(0x952B)));
+    }
+
+    #[test]
+    fn treats_zero_udp_checksum_as_missing_over_ipv4() {
+        let (source, destination) = ipv4_addresses();
+        let datagram = UDP::new(&FrameBuffer::from(clear_checksum(hex(UDP_DATAGRAM_V4), 6))).unwrap();
+
+        assert_eq!(verified(datagram, &source, &destination), Some(ChecksumStatus::Missing));
+    }
+
+    #[test]
+    fn rejects_zero_udp_checksum_over_ipv6() {
+        let (source, destination) = ipv6_addresses();
+        let datagram = UDP::new(&FrameBuffer::from(clear_checksum(hex(UDP_DATAGRAM_V6), 6))).unwrap();
+
+        assert_eq!(verified(datagram, &source, &destination), Some(ChecksumStatus::Incorrect(0x952B)));
+    }
+
+    #[test]
+    fn verifies_icmp_checksum() {
+        let (source, destination) = ipv4_addresses();
+        let good = ICMP::new(Protocol::ICMP, &FrameBuffer::from(hex(ICMP_ECHO_REQUEST))).unwrap();
+        let bad = ICMP::new(Protocol::ICMP, &FrameBuffer::from(corrupt_checksum(hex(ICMP_ECHO_REQUEST), 2))).unwrap();
+
+        assert_eq!(verified(good, &source, &destination), Some(ChecksumStatus::Correct));
+        assert_eq!(verified(bad, &source, &destination), Some(ChecksumStatus::Incorrect(0x192D)));
+    }
+
+    #[test]
+    fn verifies_icmpv6_checksum_with_pseudo_header() {
+        let (source, destination) = ipv6_addresses();
+        let good = ICMP::new(Protocol::ICMPv6, &FrameBuffer::from(hex(ICMPV6_ECHO_REQUEST))).unwrap();
+        let bad = ICMP::new(Protocol::ICMPv6, &FrameBuffer::from(corrupt_checksum(hex(ICMPV6_ECHO_REQUEST), 2))).unwrap();
+
+        assert_eq!(verified(good, &source, &destination), Some(ChecksumStatus::Correct));
+        assert_eq!(verified(bad, &source, &destination), Some(ChecksumStatus::Incorrect(0xA3E1)));
+    }
+
+    #[test]
+    fn verifies_icmp_checksum_regardless_of_offload() {
+        let (source, destination) = ipv4_addresses();
+        let mut message = ICMP::new(Protocol::ICMP, &FrameBuffer::from(hex(ICMP_ECHO_REQUEST))).unwrap();
+
+        message.verify_checksum(Some((&source, &destination)), true);
+
+        assert_eq!(message.checksum_status(), Some(ChecksumStatus::Correct));
+    }
+}
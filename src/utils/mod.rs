@@ -1,3 +1,5 @@
 pub(crate) mod input_util;
 pub(crate) mod protocol_util;
-pub(crate) mod index_util;
\ No newline at end of file
+pub(crate) mod index_util;
+pub(crate) mod x509_util;
+pub(crate) mod checksum_util;
\ No newline at end of file
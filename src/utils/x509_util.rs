@@ -0,0 +1,181 @@
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_SET: u8 = 0x31;
+const TAG_OID: u8 = 0x06;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_EXPLICIT_VERSION: u8 = 0xA0;
+
+/// Ranges are (offset, length) pairs relative to the start of the certificate
+pub struct CertificateSummary {
+    pub subject: String,
+    pub subject_range: (usize, usize),
+    pub issuer: String,
+    pub issuer_range: (usize, usize),
+    pub not_before: String,
+    pub not_before_range: (usize, usize),
+    pub not_after: String,
+    pub not_after_range: (usize, usize),
+}
+
+struct Tlv<'a> {
+    tag: u8,
+    content: &'a [u8],
+    rest: &'a [u8],
+}
+
+pub fn parse_certificate(data: &[u8]) -> Option<CertificateSummary> {
+    let certificate = read_tlv(data, TAG_SEQUENCE)?;
+    let tbs_certificate = read_tlv(certificate.content, TAG_SEQUENCE)?;
+
+    let mut fields = tbs_certificate.content;
+
+    if fields.first() == Some(&TAG_EXPLICIT_VERSION) {
+        fields = read_any_tlv(fields)?.rest;
+    }
+
+    let serial_number = read_any_tlv(fields)?;
+    let signature = read_tlv(serial_number.rest, TAG_SEQUENCE)?;
+    let issuer = read_tlv(signature.rest, TAG_SEQUENCE)?;
+    let validity = read_tlv(issuer.rest, TAG_SEQUENCE)?;
+    let subject = read_tlv(validity.rest, TAG_SEQUENCE)?;
+
+    let not_before = read_any_tlv(validity.content)?;
+    let not_after = read_any_tlv(not_before.rest)?;
+
+    Some(CertificateSummary {
+        subject: format_name(subject.content),
+        subject_range: range_within(data, subject.content),
+        issuer: format_name(issuer.content),
+        issuer_range: range_within(data, issuer.content),
+        not_before: format_time(&not_before),
+        not_before_range: range_within(data, not_before.content),
+        not_after: format_time(&not_after),
+        not_after_range: range_within(data, not_after.content),
+    })
+}
+
+fn range_within(data: &[u8], part: &[u8]) -> (usize, usize) {
+    (part.as_ptr() as usize - data.as_ptr() as usize, part.len())
+}
+
+fn read_any_tlv(data: &[u8]) -> Option<Tlv<'_>> {
+    let tag = *data.first()?;
+    let first_length_byte = *data.get(1)? as usize;
+
+    let (length, header_length) = if first_length_byte < 0x80 {
+        (first_length_byte, 2)
+    } else {
+        let length_bytes = first_length_byte & 0x7F;
+
+        if length_bytes == 0 || length_bytes > 4 || data.len() < 2 + length_bytes {
+            return None;
+        }
+
+        let length = data[2..2 + length_bytes]
+            .iter()
+            .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+
+        (length, 2 + length_bytes)
+    };
+
+    if data.len() < header_length + length {
+        return None;
+    }
+
+    Some(Tlv {
+        tag,
+        content: &data[header_length..header_length + length],
+        rest: &data[header_length + length..],
+    })
+}
+
+fn read_tlv(data: &[u8], expected_tag: u8) -> Option<Tlv<'_>> {
+    match read_any_tlv(data) {
+        Some(tlv) if tlv.tag == expected_tag => Some(tlv),
+        _ => None
+    }
+}
+
+fn format_name(mut data: &[u8]) -> String {
+    let mut components = Vec::new();
+
+    while let Some(set) = read_tlv(data, TAG_SET) {
+        let mut attributes = set.content;
+
+        while let Some(attribute) = read_tlv(attributes, TAG_SEQUENCE) {
+            if let Some(oid) = read_tlv(attribute.content, TAG_OID) {
+                if let Some(value) = read_any_tlv(oid.rest) {
+                    components.push(format!(
+                        "{}={}",
+                        attribute_name(oid.content),
+                        String::from_utf8_lossy(value.content)
+                    ));
+                }
+            }
+
+            attributes = attribute.rest;
+        }
+
+        data = set.rest;
+    }
+
+    components.join(", ")
+}
+
+fn attribute_name(oid: &[u8]) -> String {
+    match oid {
+        [0x55, 0x04, 0x03] => String::from("CN"),
+        [0x55, 0x04, 0x05] => String::from("serialNumber"),
+        [0x55, 0x04, 0x06] => String::from("C"),
+        [0x55, 0x04, 0x07] => String::from("L"),
+        [0x55, 0x04, 0x08] => String::from("ST"),
+        [0x55, 0x04, 0x0A] => String::from("O"),
+        [0x55, 0x04, 0x0B] => String::from("OU"),
+        [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x01] => String::from("emailAddress"),
+        _ => format_oid(oid)
+    }
+}
+
+fn format_oid(oid: &[u8]) -> String {
+    let mut arcs: Vec<String> = Vec::new();
+    let mut value: u64 = 0;
+
+    for byte in oid {
+        value = (value << 7) | (*byte & 0x7F) as u64;
+
+        if byte & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = if value < 80 { value / 40 } else { 2 };
+                arcs.push(first.to_string());
+                arcs.push((value - first * 40).to_string());
+            } else {
+                arcs.push(value.to_string());
+            }
+            value = 0;
+        }
+    }
+
+    arcs.join(".")
+}
+
+fn format_time(time: &Tlv) -> String {
+    let text = String::from_utf8_lossy(time.content);
+
+    if !text.is_ascii() {
+        return text.to_string();
+    }
+
+    let full = match time.tag {
+        TAG_UTC_TIME if text.len() >= 12 => {
+            let century = if text[0..2].parse::<u32>().unwrap_or(0) < 50 { "20" } else { "19" };
+            format!("{}{}", century, text)
+        },
+        TAG_GENERALIZED_TIME if text.len() >= 14 => text.to_string(),
+        _ => return text.to_string()
+    };
+
+    format!(
+        "{}-{}-{} {}:{}:{} UTC",
+        &full[0..4], &full[4..6], &full[6..8], &full[8..10], &full[10..12], &full[12..14]
+    )
+}